@@ -5,6 +5,28 @@ import ModalDictationCore
 
 setbuf(stdout, nil)
 
+// `ModalDictation check-config [path]` validates a config file without starting capture.
+if CommandLine.arguments.dropFirst().first == "check-config" {
+    let path = ConfigOverrides.removingOverrides(from: Array(CommandLine.arguments.dropFirst(2))).first
+        ?? ConfigReader.configFilePath.path
+    do {
+        let config = try ConfigReader.read(from: path, overrides: ConfigOverrides.resolve())
+        let diagnostics = try ConfigReader.diagnose(fileAt: path)
+        for diagnostic in diagnostics {
+            FileHandle.standardError.write(Data("\(path): \(diagnostic)\n".utf8))
+        }
+        guard diagnostics.isEmpty else { exit(1) }
+        print("OK: \(path)")
+        for included in config.includedFiles {
+            print("  includes \(included)")
+        }
+        exit(0)
+    } catch {
+        FileHandle.standardError.write(Data("\(path): \(error)\n".utf8))
+        exit(1)
+    }
+}
+
 let durationSeconds = 3.0
 let outputPath = "/tmp/modal-dictation-test.wav"
 
@@ -13,7 +35,9 @@ let engine = AudioCaptureEngine()
 Task {
     do {
         try ConfigReader.ensureConfigExists()
-        let config = try ConfigReader.read(from: ConfigReader.configFilePath.path)
+        let config = try ConfigReader.read(
+            from: ConfigReader.configFilePath.path, overrides: ConfigOverrides.resolve()
+        )
         let deviceUID = config.mic.deviceID
 
         print("Starting \(Int(durationSeconds))s audio capture...")
@@ -1,5 +1,6 @@
 @preconcurrency import AVFAudio
 import CoreAudio
+import os
 
 public enum AudioCaptureError: Error {
     case alreadyRunning
@@ -10,6 +11,8 @@ public actor AudioCaptureEngine: AudioCapturing {
     private let deviceManager = AudioDeviceManager()
     private var bufferContinuation: AsyncStream<AVAudioPCMBuffer>.Continuation?
     private var currentDeviceUID: String?
+    // Read from the audio thread inside the tap, so it can't be actor state.
+    private let paused = OSAllocatedUnfairLock(initialState: false)
 
     public init() {}
 
@@ -38,7 +41,9 @@ public actor AudioCaptureEngine: AudioCapturing {
         bufferContinuation = continuation
 
         let format = inputNode.outputFormat(forBus: 0)
+        let paused = paused
         inputNode.installTap(onBus: 0, bufferSize: 1024, format: format) { buffer, _ in
+            guard !paused.withLock({ $0 }) else { return }
             continuation.yield(buffer)
         }
 
@@ -62,5 +67,10 @@ public actor AudioCaptureEngine: AudioCapturing {
         bufferContinuation?.finish()
         bufferContinuation = nil
         currentDeviceUID = nil
+        paused.withLock { $0 = false }
+    }
+
+    public func setPaused(_ paused: Bool) {
+        self.paused.withLock { $0 = paused }
     }
 }
@@ -3,4 +3,6 @@
 public protocol AudioCapturing: Sendable {
     func start(deviceUID: String?) async throws -> sending AsyncStream<AVAudioPCMBuffer>
     func stop() async
+    // Drops captured audio without ending the stream, e.g. while text-to-speech is playing.
+    func setPaused(_ paused: Bool) async
 }
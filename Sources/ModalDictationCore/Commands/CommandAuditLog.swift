@@ -0,0 +1,96 @@
+import Foundation
+import os
+
+public enum CommandAuditLogError: Error, Equatable {
+    case fileOpenFailed(String)
+}
+
+// Append-only JSON-lines record of executed commands, kept apart from transcripts.
+public actor CommandAuditLog: CommandEventObserving {
+    private static let logger = Logger(subsystem: "ModalDictation", category: "CommandAuditLog")
+    public static let defaultPath = ConfigReader.configDirectory.appendingPathComponent("audit.log").path
+
+    private let path: String
+    private let maxBytes: Int
+    private let encoder: JSONEncoder
+
+    public init(path: String = CommandAuditLog.defaultPath, maxBytes: Int = 1_000_000) {
+        self.path = (path as NSString).expandingTildeInPath
+        self.maxBytes = maxBytes
+        self.encoder = JSONEncoder()
+        encoder.keyEncodingStrategy = .convertToSnakeCase
+        encoder.outputFormatting = .sortedKeys
+    }
+
+    public static func make(from config: PrivacyConfig) -> CommandAuditLog? {
+        guard config.auditCommands == true else { return nil }
+        return CommandAuditLog(path: config.auditFile ?? defaultPath)
+    }
+
+    // A failed write is logged but never fails the command that was audited.
+    public func commandExecuted(_ event: CommandEvent) async {
+        do {
+            try rotateIfNeeded()
+            try append(Entry(event))
+        } catch {
+            Self.logger.error("Failed to write audit entry: \(error)")
+        }
+    }
+
+    private func append(_ entry: Entry) throws {
+        let fm = FileManager.default
+        if !fm.fileExists(atPath: path) {
+            fm.createFile(atPath: path, contents: nil)
+        }
+        guard let handle = FileHandle(forWritingAtPath: path) else {
+            throw CommandAuditLogError.fileOpenFailed(path)
+        }
+        defer { try? handle.close() }
+        try handle.seekToEnd()
+        try handle.write(contentsOf: encoder.encode(entry) + Data("\n".utf8))
+    }
+
+    // Keeps a single previous generation at <path>.1.
+    private func rotateIfNeeded() throws {
+        let fm = FileManager.default
+        guard let size = try? fm.attributesOfItem(atPath: path)[.size] as? Int, size >= maxBytes else { return }
+        let rotated = path + ".1"
+        if fm.fileExists(atPath: rotated) {
+            try fm.removeItem(atPath: rotated)
+        }
+        try fm.moveItem(atPath: path, toPath: rotated)
+    }
+
+    struct Entry: Codable, Equatable {
+        let timestamp: String
+        let phrase: String
+        let command: String
+        let confidence: Float?
+        let succeeded: Bool
+        let handled: Bool
+        let failure: String?
+        let durationMs: Int
+
+        init(_ event: CommandEvent) {
+            timestamp = Date().ISO8601Format()
+            phrase = event.phrase
+            command = Self.describe(event.command)
+            confidence = event.confidence
+            succeeded = event.succeeded
+            handled = event.handled
+            failure = event.failure
+            let (seconds, attoseconds) = event.duration.components
+            durationMs = Int(seconds * 1_000 + attoseconds / 1_000_000_000_000_000)
+        }
+
+        private static func describe(_ command: MatchedCommand) -> String {
+            switch command {
+            case let .keystroke(key, modifiers, count):
+                let chord = (modifiers + [key]).joined(separator: "+")
+                return count > 1 ? "\(chord) x\(count)" : chord
+            case let .action(name, argument):
+                return argument.map { "\(name) \($0)" } ?? name
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+public struct CommandEvent: Sendable, Equatable {
+    public let command: MatchedCommand
+    public let phrase: String
+    public let confidence: Float?
+    public let failure: String?
+    // False when nothing acted on the command, e.g. an action no handler recognizes.
+    public let handled: Bool
+    public let duration: Duration
+
+    public init(
+        command: MatchedCommand,
+        phrase: String,
+        confidence: Float? = nil,
+        failure: String? = nil,
+        handled: Bool = true,
+        duration: Duration
+    ) {
+        self.command = command
+        self.phrase = phrase
+        self.confidence = confidence
+        self.failure = failure
+        self.handled = handled
+        self.duration = duration
+    }
+
+    public var succeeded: Bool { handled && failure == nil }
+}
+
+public protocol CommandEventObserving: Sendable {
+    func commandExecuted(_ event: CommandEvent) async
+}
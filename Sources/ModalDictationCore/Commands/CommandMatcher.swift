@@ -2,6 +2,9 @@ import Foundation
 
 public struct CommandMatcher: Sendable {
 
+    // Only these actions read a trailing number; any other action leaves it to be matched as a key.
+    private static let actionsTakingArgument: Set<String> = [HistoryAction.inject.rawValue]
+
     private let trie: PhraseTrie
     private let fuzzy: FuzzyMatcher
 
@@ -45,10 +48,13 @@ public struct CommandMatcher: Sendable {
                 modifiers = []
 
             case .action(let value):
-                results.append(.action(value))
+                index += length
+                let argument = Self.actionsTakingArgument.contains(value)
+                    ? parseActionArgument(tokens: tokens, index: &index)
+                    : nil
+                results.append(.action(value, argument: argument))
                 // Actions are self-contained — discard any preceding modifiers.
                 modifiers = []
-                index += length
             }
         }
 
@@ -60,11 +66,21 @@ public struct CommandMatcher: Sendable {
             return 1
         }
         let timesIndex = index + consumed
-        guard timesIndex < tokens.count, tokens[timesIndex].lowercased() == "times" else {
+        // "zero times" would silently swallow the keystroke, so it isn't treated as a repeat count.
+        guard number > 0, timesIndex < tokens.count, tokens[timesIndex].lowercased() == "times" else {
             return 1
         }
         index = timesIndex + 1
         return number
     }
 
+    // Trailing number selects an item for actions like "inject item three".
+    private func parseActionArgument(tokens: [String], index: inout Int) -> Int? {
+        guard let (number, consumed) = NumberParser.parse(from: tokens, startingAt: index) else {
+            return nil
+        }
+        index += consumed
+        return number
+    }
+
 }
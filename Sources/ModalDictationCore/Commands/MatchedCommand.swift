@@ -2,7 +2,7 @@ import Foundation
 
 public enum MatchedCommand: Sendable, Equatable {
     case keystroke(key: String, modifiers: [String], repeat: Int)
-    case action(String)
+    case action(String, argument: Int? = nil)
 }
 
 public enum PhraseCategory: Sendable, Equatable {
@@ -2,30 +2,62 @@ import Foundation
 
 enum NumberParser {
 
+    // Consumes the whole spoken number, so "one hundred twenty" is 120 rather than 100 followed by 20.
     static func parse(from tokens: [String], startingAt start: Int) -> (value: Int, tokensConsumed: Int)? {
         guard start < tokens.count else { return nil }
 
         if let digit = Int(tokens[start]) {
             return (digit, 1)
         }
+        if tokens[start].lowercased() == "zero" {
+            return (0, 1)
+        }
 
-        let word = tokens[start].lowercased()
+        guard let (leading, leadingConsumed) = parseBelowThousand(tokens, startingAt: start) else { return nil }
+        let thousandIndex = start + leadingConsumed
+        guard word(tokens, at: thousandIndex) == "thousand" else {
+            return (leading, leadingConsumed)
+        }
+        guard let (rest, restConsumed) = parseBelowThousand(tokens, startingAt: thousandIndex + 1) else {
+            return (leading * 1000, leadingConsumed + 1)
+        }
+        return (leading * 1000 + rest, leadingConsumed + 1 + restConsumed)
+    }
 
-        if start + 1 < tokens.count && tokens[start + 1].lowercased() == "hundred" {
-            if let ones = smallNumbers[word], ones <= 9 {
-                return (ones * 100, 2)
-            }
+    // "two hundred", "two hundred five", "fifteen hundred"
+    private static func parseBelowThousand(_ tokens: [String], startingAt start: Int) -> (value: Int, tokensConsumed: Int)? {
+        guard let (leading, leadingConsumed) = parseBelowHundred(tokens, startingAt: start) else { return nil }
+        let hundredIndex = start + leadingConsumed
+        guard word(tokens, at: hundredIndex) == "hundred" else {
+            return (leading, leadingConsumed)
         }
+        guard let (rest, restConsumed) = parseBelowHundred(tokens, startingAt: hundredIndex + 1) else {
+            return (leading * 100, leadingConsumed + 1)
+        }
+        return (leading * 100 + rest, leadingConsumed + 1 + restConsumed)
+    }
 
-        if let value = smallNumbers[word] { return (value, 1) }
-        if let value = decadeNumbers[word] { return (value, 1) }
+    // "seven", "seventeen", "seventy", "seventy seven"
+    private static func parseBelowHundred(_ tokens: [String], startingAt start: Int) -> (value: Int, tokensConsumed: Int)? {
+        guard let first = word(tokens, at: start) else { return nil }
+        if let value = smallNumbers[first] { return (value, 1) }
+        guard let tens = decadeNumbers[first] else { return nil }
+        if let next = word(tokens, at: start + 1), let ones = smallNumbers[next], ones <= 9 {
+            return (tens + ones, 2)
+        }
+        return (tens, 1)
+    }
 
-        return nil
+    private static func word(_ tokens: [String], at index: Int) -> String? {
+        index < tokens.count ? tokens[index].lowercased() : nil
     }
 
+    // Zero is parsed only on its own, never as part of a larger number.
     private static let smallNumbers: [String: Int] = [
         "one": 1, "two": 2, "three": 3, "four": 4, "five": 5,
         "six": 6, "seven": 7, "eight": 8, "nine": 9, "ten": 10,
+        "eleven": 11, "twelve": 12, "thirteen": 13, "fourteen": 14, "fifteen": 15,
+        "sixteen": 16, "seventeen": 17, "eighteen": 18, "nineteen": 19,
     ]
 
     private static let decadeNumbers: [String: Int] = [
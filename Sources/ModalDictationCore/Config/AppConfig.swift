@@ -8,13 +8,113 @@ public struct MicConfig: Sendable, Equatable {
     }
 }
 
+public struct StartupConfig: Sendable, Equatable {
+    public var mode: Mode?
+
+    public init(mode: Mode? = nil) {
+        self.mode = mode
+    }
+}
+
 public struct SpeechConfig: Sendable, Equatable {
     public var timeout: Double?
     public var autoSleepMinutes: Double?
+    public var maxUtteranceSeconds: Double?
+    public var unloadModelsAfterIdleMinutes: Double?
 
-    public init(timeout: Double? = nil, autoSleepMinutes: Double? = nil) {
+    public init(
+        timeout: Double? = nil,
+        autoSleepMinutes: Double? = nil,
+        maxUtteranceSeconds: Double? = nil,
+        unloadModelsAfterIdleMinutes: Double? = nil
+    ) {
         self.timeout = timeout
         self.autoSleepMinutes = autoSleepMinutes
+        self.maxUtteranceSeconds = maxUtteranceSeconds
+        self.unloadModelsAfterIdleMinutes = unloadModelsAfterIdleMinutes
+    }
+}
+
+public struct HistoryConfig: Sendable, Equatable {
+    public var capacity: Int?
+    public var copyToClipboard: Bool?
+    public var correctionPhrase: String?
+
+    public init(capacity: Int? = nil, copyToClipboard: Bool? = nil, correctionPhrase: String? = nil) {
+        self.capacity = capacity
+        self.copyToClipboard = copyToClipboard
+        self.correctionPhrase = correctionPhrase
+    }
+}
+
+public struct OutputConfig: Sendable, Equatable {
+    public var sinks: [TranscriptSinkKind]?
+    public var filePath: String?
+    public var command: String?
+    public var appAllowlist: [String]?
+    public var appDenylist: [String]?
+
+    public init(
+        sinks: [TranscriptSinkKind]? = nil,
+        filePath: String? = nil,
+        command: String? = nil,
+        appAllowlist: [String]? = nil,
+        appDenylist: [String]? = nil
+    ) {
+        self.sinks = sinks
+        self.filePath = filePath
+        self.command = command
+        self.appAllowlist = appAllowlist
+        self.appDenylist = appDenylist
+    }
+}
+
+public struct ReplacementsConfig: Sendable, Equatable {
+    public var global: [String: String]
+    public var perApp: [String: [String: String]]
+
+    public init(global: [String: String] = [:], perApp: [String: [String: String]] = [:]) {
+        self.global = global
+        self.perApp = perApp
+    }
+}
+
+public struct FormattingConfig: Sendable, Equatable {
+    public var locale: String?
+    public var units: Bool?
+    public var spokenPunctuation: Bool?
+    public var capitalize: Bool?
+
+    public init(
+        locale: String? = nil,
+        units: Bool? = nil,
+        spokenPunctuation: Bool? = nil,
+        capitalize: Bool? = nil
+    ) {
+        self.locale = locale
+        self.units = units
+        self.spokenPunctuation = spokenPunctuation
+        self.capitalize = capitalize
+    }
+}
+
+public struct FeedbackConfig: Sendable, Equatable {
+    public var ttsRate: Double?
+    public var ttsVoice: String?
+
+    public init(ttsRate: Double? = nil, ttsVoice: String? = nil) {
+        self.ttsRate = ttsRate
+        self.ttsVoice = ttsVoice
+    }
+}
+
+public struct PrivacyConfig: Sendable, Equatable {
+    public var auditCommands: Bool?
+    public var auditFile: String?
+
+    public init(auditCommands: Bool? = nil, auditFile: String? = nil) {
+        self.auditCommands = auditCommands
+        self.auditFile = auditFile
     }
 }
 
@@ -23,11 +123,40 @@ public struct AppConfig: Sendable, Equatable {
     public var mic: MicConfig
     public var speech: SpeechConfig
     public var commands: CommandsConfig
+    public var history: HistoryConfig
+    public var output: OutputConfig
+    public var replacements: ReplacementsConfig
+    public var formatting: FormattingConfig
+    public var feedback: FeedbackConfig
+    public var startup: StartupConfig
+    public var privacy: PrivacyConfig
+    public var includedFiles: [String] = []
+    public var profileNames: [String] = []
+    public var activeProfile: String?
 
-    public init(hotkeys: HotkeyConfig, mic: MicConfig, speech: SpeechConfig, commands: CommandsConfig) {
+    public init(
+        hotkeys: HotkeyConfig,
+        mic: MicConfig,
+        speech: SpeechConfig,
+        commands: CommandsConfig,
+        history: HistoryConfig = HistoryConfig(),
+        output: OutputConfig = OutputConfig(),
+        replacements: ReplacementsConfig = ReplacementsConfig(),
+        formatting: FormattingConfig = FormattingConfig(),
+        feedback: FeedbackConfig = FeedbackConfig(),
+        startup: StartupConfig = StartupConfig(),
+        privacy: PrivacyConfig = PrivacyConfig()
+    ) {
         self.hotkeys = hotkeys
         self.mic = mic
         self.speech = speech
         self.commands = commands
+        self.history = history
+        self.output = output
+        self.replacements = replacements
+        self.formatting = formatting
+        self.feedback = feedback
+        self.startup = startup
+        self.privacy = privacy
     }
 }
@@ -11,6 +11,32 @@ public struct CommandsConfig: Sendable, Equatable {
         self.keys = keys
     }
 
+    // Entries in `other` win on conflicting phrases.
+    func merging(_ other: CommandsConfig) -> CommandsConfig {
+        CommandsConfig(
+            actions: actions.merging(other.actions) { _, new in new },
+            modifiers: modifiers.merging(other.modifiers) { _, new in new },
+            keys: keys.merging(other.keys) { _, new in new }
+        )
+    }
+
+    // Sets have no precedence between them — a phrase claimed by two sets is a config error.
+    static func combining(_ sets: [(name: String, commands: CommandsConfig)]) throws -> CommandsConfig {
+        var owners: [String: String] = [:]
+        var combined = CommandsConfig()
+        for (name, commands) in sets {
+            for (phrase, _) in commands.categorizedEntries() {
+                let key = phrase.lowercased()
+                if let owner = owners[key], owner != name {
+                    throw ConfigError.commandConflict(phrase: phrase, sets: [owner, name])
+                }
+                owners[key] = name
+            }
+            combined = combined.merging(commands)
+        }
+        return combined
+    }
+
     func categorizedEntries() -> [(phrase: String, category: PhraseCategory)] {
         let sources: [([String: String], (String) -> PhraseCategory)] = [
             (modifiers, { .modifier($0) }),
@@ -0,0 +1,65 @@
+import Foundation
+import os
+
+// Dotted-key overrides layered over config.toml. Precedence, lowest first:
+// config file, MODAL_DICTATION_* environment variables, --set arguments.
+public enum ConfigOverrides {
+    private static let logger = Logger(subsystem: "ModalDictation", category: "ConfigOverrides")
+
+    public static let environmentPrefix = "MODAL_DICTATION_"
+
+    public static func resolve(
+        environment: [String: String] = ProcessInfo.processInfo.environment,
+        arguments: [String] = CommandLine.arguments
+    ) -> [String: String] {
+        fromEnvironment(environment).merging(fromArguments(arguments)) { _, argument in argument }
+    }
+
+    // MODAL_DICTATION_SPEECH__MAX_UTTERANCE_SECONDS=20 → speech.max_utterance_seconds = 20
+    // Unknown keys are skipped with a warning rather than rejected like `--set`: a stray variable
+    // inherited from the shell shouldn't keep the app from starting.
+    public static func fromEnvironment(_ environment: [String: String]) -> [String: String] {
+        var overrides: [String: String] = [:]
+        for (name, value) in environment where name.hasPrefix(environmentPrefix) {
+            let key = name.dropFirst(environmentPrefix.count)
+                .lowercased()
+                .replacingOccurrences(of: "__", with: ".")
+            guard ConfigSchema.isKnownKey(key) else {
+                logger.warning("Ignoring \(name): \(key) is not a config setting")
+                continue
+            }
+            overrides[key] = value
+        }
+        return overrides
+    }
+
+    // Arguments left once every `--set key=value` pair is removed, e.g. check-config's path.
+    public static func removingOverrides(from arguments: [String]) -> [String] {
+        var remaining: [String] = []
+        var index = arguments.startIndex
+        while index < arguments.endIndex {
+            if arguments[index] == "--set" {
+                index += 2
+                continue
+            }
+            remaining.append(arguments[index])
+            index += 1
+        }
+        return remaining
+    }
+
+    // --set speech.timeout=2.0, repeatable; later flags win.
+    public static func fromArguments(_ arguments: [String]) -> [String: String] {
+        var overrides: [String: String] = [:]
+        var index = arguments.startIndex
+        while index < arguments.endIndex {
+            defer { index += 1 }
+            guard arguments[index] == "--set", index + 1 < arguments.endIndex else { continue }
+            index += 1
+            let assignment = arguments[index]
+            guard let equals = assignment.firstIndex(of: "=") else { continue }
+            overrides[String(assignment[..<equals])] = String(assignment[assignment.index(after: equals)...])
+        }
+        return overrides
+    }
+}
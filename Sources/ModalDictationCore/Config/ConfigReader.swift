@@ -4,11 +4,24 @@ import TOMLKit
 public enum ConfigError: Error, CustomStringConvertible {
     case fileNotFound(String)
     case parseError(String)
+    case syntaxError(line: Int, column: Int, detail: String)
+    case includeCycle(String)
+    case unknownCommandSet(String)
+    case unknownProfile(String)
+    case commandConflict(phrase: String, sets: [String])
+    case invalidValue(key: String, detail: String)
 
     public var description: String {
         switch self {
         case .fileNotFound(let path): "Config file not found: \(path)"
         case .parseError(let detail): "Failed to parse config: \(detail)"
+        case .syntaxError(let line, let column, let detail): "Config syntax error at line \(line), column \(column): \(detail)"
+        case .includeCycle(let path): "Config include cycle at: \(path)"
+        case .unknownCommandSet(let name): "Unknown command set: \(name)"
+        case .unknownProfile(let name): "Unknown profile: \(name)"
+        case .commandConflict(let phrase, let sets):
+            "Phrase \"\(phrase)\" is defined in more than one command set: \(sets.joined(separator: ", "))"
+        case .invalidValue(let key, let detail): "Invalid value for \(key): \(detail)"
         }
     }
 }
@@ -32,28 +45,174 @@ public enum ConfigReader {
         }
     }
 
-    public static func read(from path: String) throws -> AppConfig {
+    public static func read(from path: String, overrides: [String: String] = [:]) throws -> AppConfig {
         guard FileManager.default.fileExists(atPath: path) else {
             throw ConfigError.fileNotFound(path)
         }
         let content = try String(contentsOfFile: path, encoding: .utf8)
-        return try parse(content)
+        let table = try parseTable(content)
+        // The profile is merged before the other overrides so `--set` wins over the active profile,
+        // but a `profile.active` override still picks which profile that is.
+        try applyOverrides(overrides.filter { $0.key == activeProfileKey }, to: table)
+        try applyActiveProfile(to: table)
+        try applyOverrides(overrides.filter { $0.key != activeProfileKey }, to: table)
+        var config = try parse(table: table)
+
+        let fileURL = URL(fileURLWithPath: path).standardizedFileURL
+        var ancestors: Set<String> = [fileURL.path]
+        let includes = try resolveIncludes(
+            of: table, relativeTo: fileURL.deletingLastPathComponent(), ancestors: &ancestors
+        )
+        // Included packs come first so the main file can override any of their phrases.
+        config.commands = includes.reduce(CommandsConfig()) { $0.merging($1.commands) }
+            .merging(config.commands)
+        config.includedFiles = includes.map(\.path)
+        return config
+    }
+
+    // Unknown keys and mistyped values that read(from:) skips over; included files aren't checked.
+    public static func diagnose(fileAt path: String) throws -> [ConfigDiagnostic] {
+        guard FileManager.default.fileExists(atPath: path) else {
+            throw ConfigError.fileNotFound(path)
+        }
+        return ConfigSchema.diagnose(try parseTable(String(contentsOfFile: path, encoding: .utf8)))
     }
 
     public static func parse(_ tomlString: String) throws -> AppConfig {
-        let table: TOMLTable
+        let table = try parseTable(tomlString)
+        try applyActiveProfile(to: table)
+        return try parse(table: table)
+    }
+
+    private static func parseTable(_ tomlString: String) throws -> TOMLTable {
         do {
-            table = try TOMLTable(string: tomlString)
+            return try TOMLTable(string: tomlString)
+        } catch let error as TOMLParseError {
+            throw ConfigError.syntaxError(
+                line: error.source.begin.line, column: error.source.begin.column, detail: error.description
+            )
         } catch {
             throw ConfigError.parseError(error.localizedDescription)
         }
+    }
+
+    private static let activeProfileKey = "profile.active"
+
+    private static func applyOverrides(_ overrides: [String: String], to table: TOMLTable) throws {
+        for (key, value) in overrides {
+            try setValue(ConfigSchema.overrideValue(value, forKey: key), at: key.split(separator: ".").map(String.init)[...], in: table)
+        }
+    }
+
+    // Tables are reassigned after each change so this doesn't rely on nested tables being shared references.
+    private static func setValue(_ value: any TOMLValueConvertible, at path: ArraySlice<String>, in table: TOMLTable) {
+        guard let key = path.first else { return }
+        guard path.count > 1 else {
+            table[key] = value
+            return
+        }
+        let child = table[key]?.tomlValue.table ?? TOMLTable()
+        setValue(value, at: path.dropFirst(), in: child)
+        table[key] = child
+    }
+
+    private static func applyActiveProfile(to table: TOMLTable) throws {
+        let profiles = table["profiles"]?.tomlValue.table
+        // "default" is how a switch names the base config, so a profile by that name could never be selected.
+        if profiles?[ProfileSwitcher.baseProfile] != nil {
+            throw ConfigError.invalidValue(
+                key: "profiles.\(ProfileSwitcher.baseProfile)",
+                detail: "\"\(ProfileSwitcher.baseProfile)\" is reserved for the base config"
+            )
+        }
+        guard let activeProfile = activeProfile(in: table) else { return }
+        guard let overlay = profiles?[activeProfile]?.tomlValue.table else {
+            throw ConfigError.unknownProfile(activeProfile)
+        }
+        merge(overlay, into: table)
+    }
+
+    // An empty name selects the base config, so a runtime switch can override a profile set in the file.
+    private static func activeProfile(in table: TOMLTable) -> String? {
+        table["profile"]?.tomlValue.table?["active"]?.tomlValue.string.flatMap { $0.isEmpty ? nil : $0 }
+    }
+
+    // Expects the active profile to be merged already.
+    private static func parse(table: TOMLTable) throws -> AppConfig {
+        let profiles = table["profiles"]?.tomlValue.table
+        let activeProfile = activeProfile(in: table)
 
         let hotkeys = parseHotkeyConfig(table)
         let mic = parseMicConfig(table)
-        let speech = parseSpeechConfig(table)
-        let commands = parseCommandsConfig(table)
+        let speech = try parseSpeechConfig(table)
+        let commands = try parseCommandsConfig(table)
+        let history = parseHistoryConfig(table)
+        let output = try parseOutputConfig(table)
+        let replacements = parseReplacementsConfig(table)
+        let formatting = parseFormattingConfig(table)
+        let feedback = parseFeedbackConfig(table)
+        let startup = try parseStartupConfig(table)
+        let privacy = parsePrivacyConfig(table)
 
-        return AppConfig(hotkeys: hotkeys, mic: mic, speech: speech, commands: commands)
+        var config = AppConfig(
+            hotkeys: hotkeys,
+            mic: mic,
+            speech: speech,
+            commands: commands,
+            history: history,
+            output: output,
+            replacements: replacements,
+            formatting: formatting,
+            feedback: feedback,
+            startup: startup,
+            privacy: privacy
+        )
+        config.profileNames = profiles?.map { name, _ in name }.sorted() ?? []
+        config.activeProfile = activeProfile
+        return config
+    }
+
+    private static func merge(_ overlay: TOMLTable, into base: TOMLTable) {
+        for (key, value) in overlay {
+            if let overlayChild = value.tomlValue.table, let baseChild = base[key]?.tomlValue.table {
+                merge(overlayChild, into: baseChild)
+                base[key] = baseChild
+            } else {
+                base[key] = value
+            }
+        }
+    }
+
+    // Depth-first so a pack's own includes are overridden by the pack itself.
+    // Included files contribute commands only; every other section in them is ignored.
+    private static func resolveIncludes(
+        of table: TOMLTable,
+        relativeTo directory: URL,
+        ancestors: inout Set<String>
+    ) throws -> [(path: String, commands: CommandsConfig)] {
+        let entries = table["include"]?.tomlValue.array?.compactMap { $0.tomlValue.string } ?? []
+        var result: [(path: String, commands: CommandsConfig)] = []
+
+        for entry in entries {
+            let expanded = (entry as NSString).expandingTildeInPath
+            let url = URL(fileURLWithPath: expanded, relativeTo: directory).standardizedFileURL
+            let path = url.path
+            guard !ancestors.contains(path) else { throw ConfigError.includeCycle(path) }
+            guard FileManager.default.fileExists(atPath: path) else {
+                throw ConfigError.fileNotFound(path)
+            }
+
+            let included = try parseTable(String(contentsOfFile: path, encoding: .utf8))
+            ancestors.insert(path)
+            result += try resolveIncludes(
+                of: included, relativeTo: url.deletingLastPathComponent(), ancestors: &ancestors
+            )
+            ancestors.remove(path)
+            let commands = try parseCommandsConfig(included)
+            result.append((path: path, commands: commands))
+        }
+
+        return result
     }
 
     private static func parseHotkeyConfig(_ root: TOMLTable) -> HotkeyConfig {
@@ -91,21 +250,142 @@ public enum ConfigReader {
         return MicConfig(deviceID: deviceID)
     }
 
-    private static func parseSpeechConfig(_ root: TOMLTable) -> SpeechConfig {
+    private static func parseSpeechConfig(_ root: TOMLTable) throws -> SpeechConfig {
         let speechTable = root["speech"]?.tomlValue.table
         let timeout = speechTable?["timeout"]?.tomlValue.double
         let autoSleep = speechTable?["auto_sleep_minutes"]?.tomlValue.double
-        return SpeechConfig(timeout: timeout, autoSleepMinutes: autoSleep)
+        let maxUtterance = speechTable?["max_utterance_seconds"]?.tomlValue.double
+        // The batch model needs at least a second of audio, so a shorter limit would only produce padded fragments.
+        if let maxUtterance, maxUtterance < 1 {
+            throw ConfigError.invalidValue(key: "speech.max_utterance_seconds", detail: "must be at least 1.0")
+        }
+        let unloadAfterIdle = speechTable?["unload_models_after_idle_minutes"]?.tomlValue.double
+        if let unloadAfterIdle, unloadAfterIdle <= 0 {
+            throw ConfigError.invalidValue(key: "speech.unload_models_after_idle_minutes", detail: "must be positive")
+        }
+        return SpeechConfig(
+            timeout: timeout,
+            autoSleepMinutes: autoSleep,
+            maxUtteranceSeconds: maxUtterance,
+            unloadModelsAfterIdleMinutes: unloadAfterIdle
+        )
     }
 
-    private static func parseCommandsConfig(_ root: TOMLTable) -> CommandsConfig {
+    private static func parseCommandsConfig(_ root: TOMLTable) throws -> CommandsConfig {
         let commandsTable = root["commands"]?.tomlValue.table
-        let actions = parseStringDict(commandsTable?["actions"]?.tomlValue.table)
-        let modifiers = parseStringDict(commandsTable?["modifiers"]?.tomlValue.table)
-        let keys = parseStringDict(commandsTable?["keys"]?.tomlValue.table)
+        let base = parseCommandTables(commandsTable)
+
+        let sets = parseCommandSets(root)
+        // Without an explicit list every defined set is enabled.
+        let enabledNames = commandsTable?["command_sets"]?.tomlValue.array?
+            .compactMap { $0.tomlValue.string } ?? sets.keys.sorted()
+        let enabled = try enabledNames.map { name -> (name: String, commands: CommandsConfig) in
+            guard let commands = sets[name] else { throw ConfigError.unknownCommandSet(name) }
+            return (name: name, commands: commands)
+        }
+
+        // [commands] takes part in conflict detection like any set, so a set can't be silently shadowed by it.
+        return try CommandsConfig.combining([(name: "[commands]", commands: base)] + enabled)
+    }
+
+    private static func parseCommandSets(_ root: TOMLTable) -> [String: CommandsConfig] {
+        guard let setsTable = root["command_sets"]?.tomlValue.table else { return [:] }
+        return Dictionary(uniqueKeysWithValues: setsTable.compactMap { name, value in
+            value.tomlValue.table.map { (name, parseCommandTables($0)) }
+        })
+    }
+
+    private static func parseCommandTables(_ table: TOMLTable?) -> CommandsConfig {
+        let actions = parseStringDict(table?["actions"]?.tomlValue.table)
+        let modifiers = parseStringDict(table?["modifiers"]?.tomlValue.table)
+        let keys = parseStringDict(table?["keys"]?.tomlValue.table)
         return CommandsConfig(actions: actions, modifiers: modifiers, keys: keys)
     }
 
+    private static func parseHistoryConfig(_ root: TOMLTable) -> HistoryConfig {
+        let historyTable = root["history"]?.tomlValue.table
+        let capacity = historyTable?["capacity"]?.tomlValue.int
+        let copyToClipboard = historyTable?["copy_to_clipboard"]?.tomlValue.bool
+        let correctionPhrase = historyTable?["correction_phrase"]?.tomlValue.string
+        return HistoryConfig(capacity: capacity, copyToClipboard: copyToClipboard, correctionPhrase: correctionPhrase)
+    }
+
+    private static func parseOutputConfig(_ root: TOMLTable) throws -> OutputConfig {
+        let outputTable = root["output"]?.tomlValue.table
+        let sinks = try outputTable?["sinks"]?.tomlValue.array?.map { value -> TranscriptSinkKind in
+            let name = value.tomlValue.string ?? ""
+            guard let kind = TranscriptSinkKind(rawValue: name) else {
+                throw ConfigError.invalidValue(key: "output.sinks", detail: "unknown sink \"\(name)\"")
+            }
+            return kind
+        }
+        let filePath = outputTable?["file_path"]?.tomlValue.string
+        let command = outputTable?["command"]?.tomlValue.string
+        let appAllowlist = outputTable?["app_allowlist"]?.tomlValue.array?.compactMap { $0.tomlValue.string }
+        let appDenylist = outputTable?["app_denylist"]?.tomlValue.array?.compactMap { $0.tomlValue.string }
+        let config = OutputConfig(
+            sinks: sinks,
+            filePath: filePath,
+            command: command,
+            appAllowlist: appAllowlist,
+            appDenylist: appDenylist
+        )
+        try TranscriptSinks.validate(config)
+        return config
+    }
+
+    private static func parseReplacementsConfig(_ root: TOMLTable) -> ReplacementsConfig {
+        let global = parseStringDict(root["replacements"]?.tomlValue.table)
+        guard let appsTable = root["apps"]?.tomlValue.table else {
+            return ReplacementsConfig(global: global)
+        }
+        let perApp = Dictionary(uniqueKeysWithValues: appsTable.compactMap { bundleID, value in
+            value.tomlValue.table?["replacements"]?.tomlValue.table.map { (bundleID, parseStringDict($0)) }
+        })
+        return ReplacementsConfig(global: global, perApp: perApp)
+    }
+
+    private static func parseFormattingConfig(_ root: TOMLTable) -> FormattingConfig {
+        let formattingTable = root["formatting"]?.tomlValue.table
+        let locale = formattingTable?["locale"]?.tomlValue.string
+        let units = formattingTable?["units"]?.tomlValue.bool
+        let spokenPunctuation = formattingTable?["spoken_punctuation"]?.tomlValue.bool
+        let capitalize = formattingTable?["capitalize"]?.tomlValue.bool
+        return FormattingConfig(
+            locale: locale,
+            units: units,
+            spokenPunctuation: spokenPunctuation,
+            capitalize: capitalize
+        )
+    }
+
+    private static func parseFeedbackConfig(_ root: TOMLTable) -> FeedbackConfig {
+        let ttsTable = root["feedback"]?.tomlValue.table?["tts"]?.tomlValue.table
+        let rate = ttsTable?["rate"]?.tomlValue.double
+        let voice = ttsTable?["voice"]?.tomlValue.string
+        return FeedbackConfig(ttsRate: rate, ttsVoice: voice)
+    }
+
+    private static func parseStartupConfig(_ root: TOMLTable) throws -> StartupConfig {
+        guard let name = root["startup"]?.tomlValue.table?["mode"]?.tomlValue.string else {
+            return StartupConfig()
+        }
+        // Muted is entered only by voice, so it isn't a mode to launch into.
+        guard let mode = Mode(rawValue: name), mode != .muted else {
+            throw ConfigError.invalidValue(
+                key: "startup.mode", detail: "expected \"sleeping\", \"dictation\" or \"command\", got \"\(name)\""
+            )
+        }
+        return StartupConfig(mode: mode)
+    }
+
+    private static func parsePrivacyConfig(_ root: TOMLTable) -> PrivacyConfig {
+        let privacyTable = root["privacy"]?.tomlValue.table
+        let auditCommands = privacyTable?["audit_commands"]?.tomlValue.bool
+        let auditFile = privacyTable?["audit_file"]?.tomlValue.string
+        return PrivacyConfig(auditCommands: auditCommands, auditFile: auditFile)
+    }
+
     private static func parseStringDict(_ table: TOMLTable?) -> [String: String] {
         guard let table else { return [:] }
         return Dictionary(uniqueKeysWithValues: table.compactMap { key, value in
@@ -0,0 +1,174 @@
+import TOMLKit
+
+public enum ConfigDiagnostic: Sendable, Hashable, CustomStringConvertible {
+    case unknownKey(String)
+    case typeMismatch(key: String, expected: ConfigValueKind)
+
+    public var description: String {
+        switch self {
+        case .unknownKey(let key): "Unknown key: \(key)"
+        case .typeMismatch(let key, let expected): "\(key) should be \(expected.description)"
+        }
+    }
+}
+
+public enum ConfigValueKind: Sendable, Hashable, CustomStringConvertible {
+    case string
+    case integer
+    // Read with TOMLKit's `double`, which rejects integer literals, so `timeout = 2` needs to be `2.0`.
+    case number
+    case boolean
+    case stringArray
+
+    public var description: String {
+        switch self {
+        case .string: "a string"
+        case .integer: "an integer"
+        case .number: "a number with a decimal point, such as 2.0"
+        case .boolean: "true or false"
+        case .stringArray: "a list of strings"
+        }
+    }
+
+    func matches(_ value: any TOMLValueConvertible) -> Bool {
+        let value = value.tomlValue
+        switch self {
+        case .string: return value.string != nil
+        case .integer: return value.int != nil
+        case .number: return value.double != nil
+        case .boolean: return value.bool != nil
+        case .stringArray: return value.array?.allSatisfy { $0.tomlValue.string != nil } ?? false
+        }
+    }
+}
+
+// Every key ConfigReader understands. The reader skips values of the wrong type rather than failing,
+// so check-config uses this to point out typos and mistyped values it would otherwise ignore.
+enum ConfigSchema {
+
+    // "*" stands for a user-chosen name: a phrase, command set, bundle ID or profile.
+    static let fields: [String: ConfigValueKind] = [
+        "include": .stringArray,
+        "profile.active": .string,
+        "startup.mode": .string,
+        "hotkeys.dictation_hold": .string,
+        "hotkeys.sleep_toggle": .string,
+        "hotkeys.dictation_hold_device.vendor_id": .integer,
+        "hotkeys.dictation_hold_device.product_id": .integer,
+        "hotkeys.dictation_hold_device.button": .integer,
+        "hotkeys.sleep_toggle_device.vendor_id": .integer,
+        "hotkeys.sleep_toggle_device.product_id": .integer,
+        "hotkeys.sleep_toggle_device.button": .integer,
+        "mic.device_id": .string,
+        "speech.timeout": .number,
+        "speech.auto_sleep_minutes": .number,
+        "speech.max_utterance_seconds": .number,
+        "speech.unload_models_after_idle_minutes": .number,
+        "history.capacity": .integer,
+        "history.copy_to_clipboard": .boolean,
+        "history.correction_phrase": .string,
+        "output.sinks": .stringArray,
+        "output.file_path": .string,
+        "output.command": .string,
+        "output.app_allowlist": .stringArray,
+        "output.app_denylist": .stringArray,
+        "privacy.audit_commands": .boolean,
+        "privacy.audit_file": .string,
+        "replacements.*": .string,
+        "apps.*.replacements.*": .string,
+        "feedback.tts.rate": .number,
+        "feedback.tts.voice": .string,
+        "formatting.locale": .string,
+        "formatting.units": .boolean,
+        "formatting.spoken_punctuation": .boolean,
+        "formatting.capitalize": .boolean,
+        "commands.command_sets": .stringArray,
+        "commands.actions.*": .string,
+        "commands.modifiers.*": .string,
+        "commands.keys.*": .string,
+        "command_sets.*.actions.*": .string,
+        "command_sets.*.modifiers.*": .string,
+        "command_sets.*.keys.*": .string,
+    ]
+
+    private static let patterns: [(components: [String], kind: ConfigValueKind)] = fields.map { key, kind in
+        (key.split(separator: ".").map(String.init), kind)
+    }
+
+    static func diagnose(_ table: TOMLTable) -> [ConfigDiagnostic] {
+        diagnose(table, at: []).sorted { $0.description < $1.description }
+    }
+
+    static func isKnownKey(_ key: String) -> Bool {
+        kind(at: key.split(separator: ".").map(String.init)) != nil
+    }
+
+    // Overrides arrive as text, so each is converted to the type its key expects rather than guessed from the text;
+    // otherwise `mic.device_id=123` would become an integer the reader ignores.
+    static func overrideValue(_ raw: String, forKey key: String) throws -> any TOMLValueConvertible {
+        guard let expected = kind(at: key.split(separator: ".").map(String.init)) else {
+            throw ConfigError.invalidValue(key: key, detail: "unknown setting")
+        }
+        let invalid = ConfigError.invalidValue(key: key, detail: "expected \(expected.description), got \"\(raw)\"")
+        switch expected {
+        case .string:
+            return raw
+        case .integer:
+            guard let value = Int(raw) else { throw invalid }
+            return value
+        case .number:
+            guard let value = Double(raw) else { throw invalid }
+            return value
+        case .boolean:
+            guard let value = Bool(raw) else { throw invalid }
+            return value
+        case .stringArray:
+            guard let value = (try? TOMLTable(string: "value = \(raw)"))?["value"], expected.matches(value) else {
+                throw invalid
+            }
+            return value
+        }
+    }
+
+    private static func diagnose(_ table: TOMLTable, at path: [String]) -> [ConfigDiagnostic] {
+        var diagnostics: [ConfigDiagnostic] = []
+        for (key, value) in table {
+            let childPath = path + [key]
+            let dotted = childPath.joined(separator: ".")
+            if let child = value.tomlValue.table {
+                guard isKnownTable(childPath) else {
+                    diagnostics.append(.unknownKey(dotted))
+                    continue
+                }
+                diagnostics += diagnose(child, at: childPath)
+            } else if let expected = kind(at: childPath) {
+                if !expected.matches(value) {
+                    diagnostics.append(.typeMismatch(key: dotted, expected: expected))
+                }
+            } else {
+                diagnostics.append(.unknownKey(dotted))
+            }
+        }
+        return diagnostics
+    }
+
+    private static func kind(at path: [String]) -> ConfigValueKind? {
+        let path = settingPath(path)
+        return patterns.first { matches($0.components, path) }?.kind
+    }
+
+    private static func isKnownTable(_ path: [String]) -> Bool {
+        if path == ["profiles"] || (path.count == 2 && path[0] == "profiles") { return true }
+        let path = settingPath(path)
+        return patterns.contains { $0.components.count > path.count && matches($0.components.prefix(path.count), path) }
+    }
+
+    // A profile overlays the base config, so profiles.<name>.speech.timeout is checked as speech.timeout.
+    private static func settingPath(_ path: [String]) -> [String] {
+        path.count > 2 && path[0] == "profiles" ? Array(path.dropFirst(2)) : path
+    }
+
+    private static func matches<C: Collection<String>>(_ pattern: C, _ path: [String]) -> Bool {
+        pattern.count == path.count && zip(pattern, path).allSatisfy { $0 == "*" || $0 == $1 }
+    }
+}
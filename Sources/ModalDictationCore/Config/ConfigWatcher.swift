@@ -3,12 +3,20 @@ import Foundation
 public final class ConfigWatcher: @unchecked Sendable {
     private let filePath: String
     private let onChange: @Sendable () -> Void
+    private let debounce: DispatchTimeInterval
     private let queue = DispatchQueue(label: "config-watcher", qos: .utility)
     private var fileSource: DispatchSourceFileSystemObject?
     private var dirSource: DispatchSourceFileSystemObject?
-
-    public init(filePath: String, onChange: @escaping @Sendable () -> Void) {
+    private var includeWatchers: [ConfigWatcher] = []
+    private var pendingChange: DispatchWorkItem?
+
+    public init(
+        filePath: String,
+        debounce: DispatchTimeInterval = .milliseconds(250),
+        onChange: @escaping @Sendable () -> Void
+    ) {
         self.filePath = filePath
+        self.debounce = debounce
         self.onChange = onChange
     }
 
@@ -20,6 +28,23 @@ public final class ConfigWatcher: @unchecked Sendable {
     public func stop() {
         cleanupFileSource()
         cleanupDirSource()
+        pendingChange?.cancel()
+        pendingChange = nil
+        includeWatchers.forEach { $0.stop() }
+        includeWatchers = []
+    }
+
+    // Call again after each reload — the include list may have changed.
+    // Include edits go through this watcher's debounce, so a save that touches the main file and a pack reloads once.
+    public func watchIncludes(_ paths: [String]) {
+        includeWatchers.forEach { $0.stop() }
+        includeWatchers = paths.map { path in
+            ConfigWatcher(filePath: path, debounce: .milliseconds(0)) { [weak self] in
+                guard let self else { return }
+                self.queue.async { self.scheduleChange() }
+            }
+        }
+        includeWatchers.forEach { $0.start() }
     }
 
     private func watchFile() {
@@ -40,7 +65,7 @@ public final class ConfigWatcher: @unchecked Sendable {
             if flags.contains(.delete) || flags.contains(.rename) {
                 self.cleanupFileSource()
             } else {
-                self.onChange()
+                self.scheduleChange()
             }
         }
 
@@ -69,7 +94,7 @@ public final class ConfigWatcher: @unchecked Sendable {
             guard let self else { return }
             if self.fileSource == nil && FileManager.default.fileExists(atPath: self.filePath) {
                 self.watchFile()
-                self.onChange()
+                self.scheduleChange()
             }
         }
 
@@ -81,6 +106,15 @@ public final class ConfigWatcher: @unchecked Sendable {
         source.resume()
     }
 
+    // Editors save in several steps (swap/backup file, rename, write), so coalesce them into one reload.
+    // Only the watched path itself triggers a change; backup files like config.toml~ are never considered.
+    private func scheduleChange() {
+        pendingChange?.cancel()
+        let work = DispatchWorkItem { [weak self] in self?.onChange() }
+        pendingChange = work
+        queue.asyncAfter(deadline: .now() + debounce, execute: work)
+    }
+
     private func cleanupFileSource() {
         fileSource?.cancel()
         fileSource = nil
@@ -0,0 +1,90 @@
+import Foundation
+import os
+
+// Owns the effective config for a config file and the overrides layered on it, including the active profile.
+// Every reload or profile switch is broadcast to all subscribers.
+public actor ProfileSwitcher {
+    private static let logger = Logger(subsystem: "ModalDictation", category: "ProfileSwitcher")
+
+    public static let actionPrefix = "profile:"
+    public static let baseProfile = "default"
+
+    private let path: String
+    private var overrides: [String: String]
+    public private(set) var config: AppConfig
+    private var subscribers: [UUID: AsyncStream<AppConfig>.Continuation] = [:]
+    private var watcher: ConfigWatcher?
+
+    public init(path: String, overrides: [String: String] = [:]) throws {
+        self.path = path
+        self.overrides = overrides
+        self.config = try ConfigReader.read(from: path, overrides: overrides)
+    }
+
+    public func updates() -> AsyncStream<AppConfig> {
+        let (stream, continuation) = AsyncStream.makeStream(of: AppConfig.self)
+        let id = UUID()
+        subscribers[id] = continuation
+        continuation.onTermination = { [weak self] _ in
+            Task { await self?.unsubscribe(id) }
+        }
+        return stream
+    }
+
+    public func activate(_ profile: String) throws {
+        var updated = overrides
+        updated["profile.active"] = profile == Self.baseProfile ? "" : profile
+        // Read before committing so an unknown profile leaves the current one in place.
+        let newConfig = try ConfigReader.read(from: path, overrides: updated)
+        overrides = updated
+        publish(newConfig)
+    }
+
+    // Reloads on edits to the config file or any file it includes. A file that fails to parse keeps the
+    // current config in place.
+    public func startWatching() {
+        guard watcher == nil else { return }
+        let watcher = ConfigWatcher(filePath: path) { [weak self] in
+            Task {
+                do {
+                    try await self?.reload()
+                } catch {
+                    Self.logger.error("Config reload failed: \(error)")
+                }
+            }
+        }
+        watcher.start()
+        watcher.watchIncludes(config.includedFiles)
+        self.watcher = watcher
+    }
+
+    public func stopWatching() {
+        watcher?.stop()
+        watcher = nil
+    }
+
+    // Called by the watcher started in startWatching(); keeps overrides and the active profile.
+    public func reload() throws {
+        publish(try ConfigReader.read(from: path, overrides: overrides))
+    }
+
+    public func handle(_ command: MatchedCommand) async throws -> Bool {
+        guard case let .action(name, _) = command, name.hasPrefix(Self.actionPrefix) else { return false }
+        try activate(String(name.dropFirst(Self.actionPrefix.count)))
+        return true
+    }
+
+    private func publish(_ newConfig: AppConfig) {
+        if newConfig.includedFiles != config.includedFiles {
+            watcher?.watchIncludes(newConfig.includedFiles)
+        }
+        config = newConfig
+        for continuation in subscribers.values {
+            continuation.yield(newConfig)
+        }
+    }
+
+    private func unsubscribe(_ id: UUID) {
+        subscribers[id] = nil
+    }
+}
@@ -0,0 +1,7 @@
+public protocol SpeechSynthesizing: Sendable {
+    func speak(_ text: String) async
+}
+
+public protocol UserNotifying: Sendable {
+    func notify(_ message: String) async
+}
@@ -0,0 +1,51 @@
+import AVFoundation
+
+public final class SystemSpeechSynthesizer: NSObject, SpeechSynthesizing, AVSpeechSynthesizerDelegate, @unchecked Sendable {
+    // Only touched on the main queue.
+    private let synthesizer = AVSpeechSynthesizer()
+    private var pending: (utterance: AVSpeechUtterance, continuation: CheckedContinuation<Void, Never>)?
+    private let rate: Float?
+    private let voice: String?
+
+    public init(config: FeedbackConfig = FeedbackConfig()) {
+        self.rate = config.ttsRate.map(Float.init)
+        self.voice = config.ttsVoice
+        super.init()
+        synthesizer.delegate = self
+    }
+
+    // Returns once the utterance has finished playing, so callers can keep the mic paused until then.
+    public func speak(_ text: String) async {
+        await withCheckedContinuation { (continuation: CheckedContinuation<Void, Never>) in
+            DispatchQueue.main.async { [self] in
+                let utterance = AVSpeechUtterance(string: text)
+                if let rate {
+                    utterance.rate = min(max(rate, AVSpeechUtteranceMinimumSpeechRate), AVSpeechUtteranceMaximumSpeechRate)
+                }
+                // Accept either a full voice identifier or a language code like "en-GB".
+                if let voice {
+                    utterance.voice = AVSpeechSynthesisVoice(identifier: voice) ?? AVSpeechSynthesisVoice(language: voice)
+                }
+                pending?.continuation.resume()
+                pending = (utterance, continuation)
+                synthesizer.stopSpeaking(at: .immediate)
+                synthesizer.speak(utterance)
+            }
+        }
+    }
+
+    public func speechSynthesizer(_ synthesizer: AVSpeechSynthesizer, didFinish utterance: AVSpeechUtterance) {
+        DispatchQueue.main.async { self.finish(utterance) }
+    }
+
+    public func speechSynthesizer(_ synthesizer: AVSpeechSynthesizer, didCancel utterance: AVSpeechUtterance) {
+        DispatchQueue.main.async { self.finish(utterance) }
+    }
+
+    // A cancelled earlier utterance must not resume the caller of a newer one.
+    private func finish(_ utterance: AVSpeechUtterance) {
+        guard let pending, pending.utterance === utterance else { return }
+        self.pending = nil
+        pending.continuation.resume()
+    }
+}
@@ -0,0 +1,29 @@
+import Foundation
+import os
+
+// Posts through osascript because UNUserNotificationCenter requires an app bundle, which the CLI doesn't have.
+public struct SystemUserNotifier: UserNotifying {
+    private static let logger = Logger(subsystem: "ModalDictation", category: "SystemUserNotifier")
+
+    private let title: String
+
+    public init(title: String = "ModalDictation") {
+        self.title = title
+    }
+
+    public func notify(_ message: String) async {
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: "/usr/bin/osascript")
+        process.arguments = [
+            "-e", "on run argv",
+            "-e", "display notification (item 1 of argv) with title (item 2 of argv)",
+            "-e", "end run",
+            message, title,
+        ]
+        do {
+            try process.run()
+        } catch {
+            Self.logger.error("Failed to post notification: \(error)")
+        }
+    }
+}
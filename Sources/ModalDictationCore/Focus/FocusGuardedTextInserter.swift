@@ -0,0 +1,53 @@
+import os
+
+public enum FocusGuardError: Error, Equatable {
+    case refused(bundleID: String?)
+}
+
+public struct FocusGuardedTextInserter: TextInserting, FocusGuarding {
+    private static let logger = Logger(subsystem: "ModalDictation", category: "FocusGuardedTextInserter")
+
+    private let inner: any TextInserting
+    private let allowlist: Set<String>?
+    private let denylist: Set<String>
+    private let focus: any FrontmostAppProviding
+    private let notifier: (any UserNotifying)?
+
+    public init(
+        inner: any TextInserting,
+        allowlist: [String]? = nil,
+        denylist: [String]? = nil,
+        focus: any FrontmostAppProviding = WorkspaceFrontmostAppProvider(),
+        notifier: (any UserNotifying)? = SystemUserNotifier()
+    ) {
+        self.inner = inner
+        self.allowlist = allowlist.map(Set.init)
+        self.denylist = Set(denylist ?? [])
+        self.focus = focus
+        self.notifier = notifier
+    }
+
+    public var typesIntoFocusedApp: Bool { inner.typesIntoFocusedApp }
+
+    // Focus is checked at insert time, not session start, since the user may switch apps mid-dictation.
+    // A refusal is thrown so callers don't record text that never landed; sessions treat it as non-fatal.
+    public func insert(_ text: String) async throws {
+        try await checkFocus()
+        try await inner.insert(text)
+    }
+
+    public func checkFocus() async throws {
+        let bundleID = await focus.frontmostBundleID()
+        guard isAllowed(bundleID) else {
+            Self.logger.warning("Refusing to insert into \(bundleID ?? "unknown app")")
+            await notifier?.notify("Dictation not inserted into \(bundleID ?? "this app")")
+            throw FocusGuardError.refused(bundleID: bundleID)
+        }
+    }
+
+    func isAllowed(_ bundleID: String?) -> Bool {
+        if let bundleID, denylist.contains(bundleID) { return false }
+        guard let allowlist else { return true }
+        return bundleID.map(allowlist.contains) ?? false
+    }
+}
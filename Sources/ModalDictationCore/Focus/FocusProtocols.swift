@@ -0,0 +1,8 @@
+public protocol FrontmostAppProviding: Sendable {
+    func frontmostBundleID() async -> String?
+}
+
+// For callers that send keystrokes to the focused app rather than inserting text, so they honor the same lists.
+public protocol FocusGuarding: Sendable {
+    func checkFocus() async throws
+}
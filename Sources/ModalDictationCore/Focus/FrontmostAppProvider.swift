@@ -0,0 +1,9 @@
+import AppKit
+
+public struct WorkspaceFrontmostAppProvider: FrontmostAppProviding {
+    public init() {}
+
+    public func frontmostBundleID() async -> String? {
+        await MainActor.run { NSWorkspace.shared.frontmostApplication?.bundleIdentifier }
+    }
+}
@@ -0,0 +1,49 @@
+import os
+
+public struct CapitalizationFormatter: TranscriptFormatting {
+    // Whether the previous utterance ended a sentence, since each utterance is formatted on its own.
+    private let previousEndedSentence = OSAllocatedUnfairLock(initialState: true)
+
+    public init() {}
+
+    public func format(_ text: String) async -> String {
+        let startsSentence = previousEndedSentence.withLock { $0 }
+        let (result, endsSentence) = Self.capitalize(text, startsSentence: startsSentence)
+        previousEndedSentence.withLock { $0 = endsSentence }
+        return result
+    }
+
+    static func apply(to text: String) -> String {
+        capitalize(text, startsSentence: true).text
+    }
+
+    // Capitalizes the first letter of each sentence. A terminator only ends a sentence when
+    // followed by whitespace, so "3.5" and "example.com" are left alone. The end of the text
+    // counts as whitespace for the next utterance.
+    static func capitalize(_ text: String, startsSentence: Bool) -> (text: String, endsSentence: Bool) {
+        var result = ""
+        var capitalizeNext = startsSentence
+        var sawTerminator = false
+
+        for character in text {
+            if character.isLetter {
+                result += capitalizeNext ? character.uppercased() : String(character)
+                capitalizeNext = false
+                sawTerminator = false
+                continue
+            }
+            result.append(character)
+            if character.isNewline {
+                capitalizeNext = true
+            } else if character.isWhitespace {
+                capitalizeNext = capitalizeNext || sawTerminator
+            } else if ".?!".contains(character) {
+                sawTerminator = true
+            } else if !"(\"'".contains(character) {
+                capitalizeNext = false
+                sawTerminator = false
+            }
+        }
+        return (result, capitalizeNext || sawTerminator)
+    }
+}
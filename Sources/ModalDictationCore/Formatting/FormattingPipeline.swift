@@ -0,0 +1,37 @@
+import Foundation
+
+public struct FormattingPipeline: TranscriptFormatting {
+    private let stages: [any TranscriptFormatting]
+
+    public init(stages: [any TranscriptFormatting]) {
+        self.stages = stages
+    }
+
+    // Replacements run first so user-defined phrases win over built-in rules.
+    public static func make(from config: AppConfig) -> FormattingPipeline {
+        var stages: [any TranscriptFormatting] = []
+        if !config.replacements.global.isEmpty || !config.replacements.perApp.isEmpty {
+            stages.append(ReplacementFormatter(config: config.replacements))
+        }
+        // Off unless asked for: the recognizer already punctuates, and this rewrites words like "period" in prose.
+        if config.formatting.spokenPunctuation ?? false {
+            stages.append(SpokenPunctuationFormatter())
+        }
+        if config.formatting.units ?? true {
+            let locale = config.formatting.locale.map(Locale.init(identifier:)) ?? .current
+            stages.append(UnitsFormatter(locale: locale))
+        }
+        if config.formatting.capitalize ?? true {
+            stages.append(CapitalizationFormatter())
+        }
+        return FormattingPipeline(stages: stages)
+    }
+
+    public func format(_ text: String) async -> String {
+        var result = text
+        for stage in stages {
+            result = await stage.format(result)
+        }
+        return result
+    }
+}
@@ -0,0 +1,3 @@
+public protocol TranscriptFormatting: Sendable {
+    func format(_ text: String) async -> String
+}
@@ -0,0 +1,64 @@
+import Foundation
+
+public struct ReplacementFormatter: TranscriptFormatting {
+    private let global: [String: String]
+    private let perApp: [String: [String: String]]
+    private let focus: any FrontmostAppProviding
+
+    public init(config: ReplacementsConfig, focus: any FrontmostAppProviding = WorkspaceFrontmostAppProvider()) {
+        self.global = config.global
+        self.perApp = config.perApp
+        self.focus = focus
+    }
+
+    public func format(_ text: String) async -> String {
+        var table = global
+        if let bundleID = await focus.frontmostBundleID(), let overrides = perApp[bundleID] {
+            table.merge(overrides) { _, appSpecific in appSpecific }
+        }
+        return Self.apply(table, to: text)
+    }
+
+    // One pass over the text, so an expansion is never rewritten by a shorter phrase that appears inside it.
+    // Alternatives are tried longest first so "work signature" wins over a bare "signature".
+    static func apply(_ replacements: [String: String], to text: String) -> String {
+        let expansions = Dictionary(
+            replacements.map { ($0.key.lowercased(), $0.value) }, uniquingKeysWith: { first, _ in first }
+        )
+        let phrases = expansions.keys.filter { !$0.isEmpty }.sorted { $0.count > $1.count }
+        guard !phrases.isEmpty,
+              let regex = try? NSRegularExpression(
+                  pattern: phrases.map(boundedPattern(for:)).joined(separator: "|"), options: .caseInsensitive
+              )
+        else { return text }
+
+        var result = ""
+        var cursor = text.startIndex
+        for match in regex.matches(in: text, range: NSRange(text.startIndex..., in: text)) {
+            guard let range = Range(match.range, in: text) else { continue }
+            result += text[cursor..<range.lowerBound]
+            result += expansions[text[range].lowercased()] ?? String(text[range])
+            cursor = range.upperBound
+        }
+        result += text[cursor...]
+        return result
+    }
+
+    // Whole words only, but "\b" never matches next to a symbol, so ":shrug:" would never match.
+    // A boundary is required only on a side where the phrase itself starts or ends with a word character.
+    static func boundedPattern(for phrase: String) -> String {
+        let wordCharacter = "[\\p{L}\\p{N}_]"
+        var pattern = NSRegularExpression.escapedPattern(for: phrase)
+        if let first = phrase.first, isWordCharacter(first) {
+            pattern = "(?<!\(wordCharacter))" + pattern
+        }
+        if let last = phrase.last, isWordCharacter(last) {
+            pattern += "(?!\(wordCharacter))"
+        }
+        return pattern
+    }
+
+    private static func isWordCharacter(_ character: Character) -> Bool {
+        character.isLetter || character.isNumber || character == "_"
+    }
+}
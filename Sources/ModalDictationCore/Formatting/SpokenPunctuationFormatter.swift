@@ -0,0 +1,91 @@
+import Foundation
+
+public struct SpokenPunctuationFormatter: TranscriptFormatting {
+    public init() {}
+
+    public func format(_ text: String) async -> String {
+        Self.apply(to: text)
+    }
+
+    static func apply(to text: String) -> String {
+        let tokens = text.split(separator: " ").map(String.init)
+        var output = ""
+        var attachNext = false
+
+        func appendWord(_ word: String) {
+            if !output.isEmpty && !attachNext { output += " " }
+            output += word
+            attachNext = false
+        }
+
+        var index = 0
+        while index < tokens.count {
+            // "literal comma" types the word itself; before any other word "literal" is just dictated.
+            if normalized(tokens[index]) == literalWord, let (_, consumed) = matchMark(tokens, at: index + 1) {
+                for word in tokens[(index + 1)...(index + consumed)] {
+                    appendWord(word)
+                }
+                index += consumed + 1
+                continue
+            }
+            guard let (mark, consumed) = matchMark(tokens, at: index) else {
+                appendWord(tokens[index])
+                index += 1
+                continue
+            }
+            switch mark {
+            case let .trailing(symbol):
+                output = trimmingTrailingSpaces(output) + symbol
+            case let .opening(symbol):
+                appendWord(symbol)
+                attachNext = true
+            case let .lineBreak(symbol):
+                output = trimmingTrailingSpaces(output) + symbol
+                attachNext = true
+            }
+            index += consumed
+        }
+        return output
+    }
+
+    private static func matchMark(_ tokens: [String], at index: Int) -> (mark: Mark, tokensConsumed: Int)? {
+        guard index < tokens.count else { return nil }
+        if index + 1 < tokens.count,
+           let mark = marks[normalized(tokens[index]) + " " + normalized(tokens[index + 1])] {
+            return (mark, 2)
+        }
+        return marks[normalized(tokens[index])].map { ($0, 1) }
+    }
+
+    // The recognizer sometimes punctuates the spoken word itself ("Comma."), so compare bare lowercase words.
+    private static func normalized(_ token: String) -> String {
+        token.lowercased().trimmingCharacters(in: .punctuationCharacters)
+    }
+
+    private static func trimmingTrailingSpaces(_ text: String) -> String {
+        String(text.reversed().drop(while: { $0 == " " }).reversed())
+    }
+
+    private enum Mark {
+        case trailing(String)
+        case opening(String)
+        case lineBreak(String)
+    }
+
+    private static let literalWord = "literal"
+
+    private static let marks: [String: Mark] = [
+        "comma": .trailing(","),
+        "period": .trailing("."),
+        "full stop": .trailing("."),
+        "question mark": .trailing("?"),
+        "exclamation mark": .trailing("!"),
+        "exclamation point": .trailing("!"),
+        "colon": .trailing(":"),
+        "semicolon": .trailing(";"),
+        "close paren": .trailing(")"),
+        "open paren": .opening("("),
+        "new line": .lineBreak("\n"),
+        "new paragraph": .lineBreak("\n\n"),
+    ]
+}
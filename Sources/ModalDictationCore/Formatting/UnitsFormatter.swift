@@ -0,0 +1,103 @@
+import Foundation
+
+public struct UnitsFormatter: TranscriptFormatting {
+    private let locale: Locale
+
+    public init(locale: Locale = .current) {
+        self.locale = locale
+    }
+
+    public func format(_ text: String) async -> String {
+        Self.apply(to: text, locale: locale)
+    }
+
+    static func apply(to text: String, locale: Locale) -> String {
+        let tokens = text.split(separator: " ").map(String.init)
+        var output: [String] = []
+        var index = 0
+        while index < tokens.count {
+            if let (value, consumed) = parseAmount(tokens, startingAt: index), index + consumed < tokens.count {
+                let (word, trailing) = splitTrailingPunctuation(tokens[index + consumed])
+                if let unit = units[word.lowercased()] {
+                    output.append(render(value, as: unit, locale: locale) + trailing)
+                    index += consumed + 1
+                    continue
+                }
+            }
+            output.append(tokens[index])
+            index += 1
+        }
+        return output.joined(separator: " ")
+    }
+
+    // Accepts digits ("3.5") or spoken numbers with an optional "point" and digit words ("three point five").
+    private static func parseAmount(_ tokens: [String], startingAt start: Int) -> (value: Double, tokensConsumed: Int)? {
+        if tokens[start].allSatisfy({ $0.isNumber || $0 == "." }), let value = Double(tokens[start]) {
+            return (value, 1)
+        }
+        guard let (whole, consumed) = NumberParser.parse(from: tokens, startingAt: start) else { return nil }
+
+        var index = start + consumed
+        guard index < tokens.count, tokens[index].lowercased() == "point" else {
+            return (Double(whole), consumed)
+        }
+        index += 1
+        var fraction = ""
+        while case let (digit, 1)? = NumberParser.parse(from: tokens, startingAt: index), digit <= 9 {
+            fraction += String(digit)
+            index += 1
+        }
+        guard !fraction.isEmpty, let value = Double("\(whole).\(fraction)") else {
+            return (Double(whole), consumed)
+        }
+        return (value, index - start)
+    }
+
+    private static func splitTrailingPunctuation(_ token: String) -> (word: String, trailing: String) {
+        let word = token.reversed().drop(while: { $0.isPunctuation }).reversed()
+        return (String(word), String(token.dropFirst(word.count)))
+    }
+
+    private static func render(_ value: Double, as unit: SpokenUnit, locale: Locale) -> String {
+        let formatter = NumberFormatter()
+        formatter.locale = locale
+        switch unit {
+        case .percent:
+            formatter.numberStyle = .decimal
+            return (formatter.string(from: value as NSNumber) ?? "\(value)") + "%"
+        case let .currency(code):
+            formatter.numberStyle = .currency
+            formatter.currencyCode = code
+            formatter.minimumFractionDigits = value.rounded() == value ? 0 : 2
+            return formatter.string(from: value as NSNumber) ?? "\(value) \(code)"
+        case let .measure(symbol):
+            formatter.numberStyle = .decimal
+            formatter.maximumFractionDigits = 6
+            return (formatter.string(from: value as NSNumber) ?? "\(value)") + " " + symbol
+        }
+    }
+
+    private enum SpokenUnit {
+        case percent
+        case currency(String)
+        case measure(String)
+    }
+
+    private static let units: [String: SpokenUnit] = [
+        "percent": .percent,
+        "euro": .currency("EUR"), "euros": .currency("EUR"),
+        "dollar": .currency("USD"), "dollars": .currency("USD"),
+        "yen": .currency("JPY"),
+        "kilometer": .measure("km"), "kilometers": .measure("km"),
+        "kilometre": .measure("km"), "kilometres": .measure("km"),
+        "meter": .measure("m"), "meters": .measure("m"),
+        "metre": .measure("m"), "metres": .measure("m"),
+        "centimeter": .measure("cm"), "centimeters": .measure("cm"),
+        "millimeter": .measure("mm"), "millimeters": .measure("mm"),
+        "mile": .measure("mi"), "miles": .measure("mi"),
+        "kilogram": .measure("kg"), "kilograms": .measure("kg"),
+        "gram": .measure("g"), "grams": .measure("g"),
+        "liter": .measure("L"), "liters": .measure("L"),
+        "litre": .measure("L"), "litres": .measure("L"),
+    ]
+}
@@ -0,0 +1,176 @@
+import Foundation
+
+public enum HistoryAction: String, Sendable, Equatable, CaseIterable {
+    case pasteLast = "history:paste_last"
+    case inject = "history:inject"
+    case readBack = "history:read_back"
+    case scratch = "history:scratch"
+    case show = "history:show"
+}
+
+public struct HistoryActionHandler: Sendable {
+    private let history: TranscriptHistory
+    private let inserter: any TextInserting
+    private let clipboard: any ClipboardWriting
+    private let emitter: any KeystrokeEmitting
+    private let focusGuard: (any FocusGuarding)?
+    private let copyToClipboard: Bool
+    private let speaker: (any SpeechSynthesizing)?
+    private let capture: (any AudioCapturing)?
+    private let correctionPhrase: String?
+    private let presenter: any HistoryPresenting
+
+    public init(
+        history: TranscriptHistory,
+        inserter: any TextInserting = PasteboardTextInserter(),
+        clipboard: any ClipboardWriting = PasteboardClipboardWriter(),
+        emitter: any KeystrokeEmitting = LiveKeystrokeEmitter(),
+        focusGuard: (any FocusGuarding)? = nil,
+        copyToClipboard: Bool = false,
+        speaker: (any SpeechSynthesizing)? = nil,
+        capture: (any AudioCapturing)? = nil,
+        correctionPhrase: String? = nil,
+        presenter: any HistoryPresenting = StandardOutputHistoryPresenter()
+    ) {
+        self.history = history
+        self.inserter = inserter
+        self.clipboard = clipboard
+        self.emitter = emitter
+        self.focusGuard = focusGuard
+        self.copyToClipboard = copyToClipboard
+        self.speaker = speaker
+        self.capture = capture
+        self.correctionPhrase = correctionPhrase?.lowercased()
+        self.presenter = presenter
+    }
+
+    public static func make(
+        from config: AppConfig,
+        history: TranscriptHistory,
+        speaker: (any SpeechSynthesizing)? = nil,
+        capture: (any AudioCapturing)? = nil
+    ) -> HistoryActionHandler {
+        let guarded = FocusGuardedTextInserter(
+            inner: PasteboardTextInserter(),
+            allowlist: config.output.appAllowlist,
+            denylist: config.output.appDenylist
+        )
+        return HistoryActionHandler(
+            history: history,
+            inserter: guarded,
+            focusGuard: guarded,
+            copyToClipboard: config.history.copyToClipboard ?? false,
+            speaker: speaker,
+            capture: capture,
+            correctionPhrase: config.history.correctionPhrase
+        )
+    }
+
+    public func handle(_ command: MatchedCommand) async throws -> Bool {
+        guard case let .action(name, argument) = command,
+              let action = HistoryAction(rawValue: name)
+        else { return false }
+        return try await perform(action, argument: argument)
+    }
+
+    // "change <target> to <replacement>" edits the last dictation in place. Returns false when the utterance
+    // isn't a correction or the target isn't in the last dictation, so the caller can dictate it instead.
+    // Arrow keys and backspace behave the same in standard macOS text fields, so there are no per-app
+    // strategies; apps with other cursor semantics (terminals, vim) aren't supported.
+    // Throws FocusGuardError before any keystroke when the focused app is refused, so nothing is half-edited.
+    public func correct(_ utterance: String) async throws -> Bool {
+        guard let (target, replacement) = parseCorrection(utterance),
+              let last = await history.last,
+              let range = Self.lastWholeWordRange(of: target, in: last)
+        else { return false }
+
+        try await focusGuard?.checkFocus()
+        // Assumes the cursor still sits where the last dictation ended.
+        let trailing = last.distance(from: range.upperBound, to: last.endIndex)
+        try emitRepeated("left", trailing)
+        try emitRepeated("backspace", last.distance(from: range.lowerBound, to: range.upperBound))
+        try await inserter.insert(replacement)
+        try emitRepeated("right", trailing)
+
+        await history.replaceLast(with: last.replacingCharacters(in: range, with: replacement))
+        return true
+    }
+
+    private func parseCorrection(_ utterance: String) -> (target: String, replacement: String)? {
+        guard let correctionPhrase else { return nil }
+        let text = utterance.trimmingCharacters(in: .whitespacesAndNewlines.union(.punctuationCharacters))
+        guard text.lowercased().hasPrefix(correctionPhrase + " ") else { return nil }
+
+        let body = text.dropFirst(correctionPhrase.count + 1)
+        guard let separator = body.range(of: " to ", options: .caseInsensitive) else { return nil }
+        let target = body[..<separator.lowerBound].trimmingCharacters(in: .whitespaces)
+        let replacement = body[separator.upperBound...].trimmingCharacters(in: .whitespaces)
+        guard !target.isEmpty, !replacement.isEmpty else { return nil }
+        return (target, replacement)
+    }
+
+    // Whole words only, so "change a to the" leaves "today" alone.
+    private static func lastWholeWordRange(of target: String, in text: String) -> Range<String.Index>? {
+        guard let regex = try? NSRegularExpression(
+            pattern: ReplacementFormatter.boundedPattern(for: target), options: .caseInsensitive
+        ) else { return nil }
+        return regex.matches(in: text, range: NSRange(text.startIndex..., in: text))
+            .last
+            .flatMap { Range($0.range, in: text) }
+    }
+
+    private func emitRepeated(_ key: String, _ count: Int) throws {
+        guard count > 0 else { return }
+        try emitter.emit(command: .keystroke(key: key, modifiers: [], repeat: count))
+    }
+
+    // Nothing recorded at the requested position is a no-op rather than a failure, so the session keeps running.
+    // Returns false only for an action this handler can't perform at all, such as read-back without a speaker.
+    private func perform(_ action: HistoryAction, argument: Int?) async throws -> Bool {
+        switch action {
+        case .pasteLast:
+            try await deliver(await history.last)
+        case .inject:
+            try await deliver(await history.item(argument ?? 1))
+        case .readBack:
+            guard let speaker else { return false }
+            if let text = await history.last {
+                // The mic would otherwise hear the read-back and transcribe it, or fire a voice trigger.
+                await capture?.setPaused(true)
+                await speaker.speak(text)
+                await capture?.setPaused(false)
+            }
+        case .scratch:
+            try await scratch()
+        case .show:
+            await presenter.present(Array(await history.entries.reversed()))
+        }
+        return true
+    }
+
+    // One backspace per character removes the paste as long as the cursor hasn't moved since.
+    private func scratch() async throws {
+        guard let text = await history.last else { return }
+        do {
+            try await focusGuard?.checkFocus()
+        } catch is FocusGuardError {
+            // Backspacing into a refused app could delete its text, so the entry stays for a later scratch.
+            return
+        }
+        try emitRepeated("backspace", text.count)
+        _ = await history.removeLast()
+    }
+
+    private func deliver(_ text: String?) async throws {
+        guard let text else { return }
+        if copyToClipboard {
+            try clipboard.copy(text)
+            return
+        }
+        do {
+            try await inserter.insert(text)
+        } catch is FocusGuardError {
+            // The guard already told the user; a refused paste shouldn't end the session.
+        }
+    }
+}
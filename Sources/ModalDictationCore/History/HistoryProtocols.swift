@@ -0,0 +1,4 @@
+public protocol HistoryPresenting: Sendable {
+    // Entries arrive most recent first, so position n is what "inject item n" pastes.
+    func present(_ entries: [String]) async
+}
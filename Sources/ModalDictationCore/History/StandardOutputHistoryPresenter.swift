@@ -0,0 +1,12 @@
+import Foundation
+
+public struct StandardOutputHistoryPresenter: HistoryPresenting {
+    public init() {}
+
+    public func present(_ entries: [String]) async {
+        let lines = entries.isEmpty
+            ? ["History is empty"]
+            : entries.enumerated().map { "\($0.offset + 1). \($0.element)" }
+        try? FileHandle.standardOutput.write(contentsOf: Data((lines.joined(separator: "\n") + "\n").utf8))
+    }
+}
@@ -0,0 +1,38 @@
+public actor TranscriptHistory {
+
+    private let capacity: Int
+    public private(set) var entries: [String] = []
+
+    public init(capacity: Int = 20) {
+        self.capacity = max(1, capacity)
+    }
+
+    public init(config: HistoryConfig) {
+        self.init(capacity: config.capacity ?? 20)
+    }
+
+    public var last: String? { entries.last }
+
+    public func record(_ text: String) {
+        guard !text.isEmpty else { return }
+        entries.append(text)
+        if entries.count > capacity {
+            entries.removeFirst(entries.count - capacity)
+        }
+    }
+
+    public func removeLast() -> String? {
+        entries.popLast()
+    }
+
+    public func replaceLast(with text: String) {
+        guard !entries.isEmpty else { return }
+        entries[entries.count - 1] = text
+    }
+
+    // Items are numbered the way they are spoken: "item one" is the most recent transcript.
+    public func item(_ number: Int) -> String? {
+        guard number >= 1, number <= entries.count else { return nil }
+        return entries[entries.count - number]
+    }
+}
@@ -35,6 +35,8 @@ public struct CGEventPasteStrategy: PasteStrategy {
 }
 
 public struct PasteboardTextInserter: TextInserting {
+    public let typesIntoFocusedApp = true
+
     public init() {}
 
     public func insert(_ text: String) async throws {
@@ -42,6 +44,18 @@ public struct PasteboardTextInserter: TextInserting {
     }
 }
 
+public struct PasteboardClipboardWriter: ClipboardWriting {
+    public init() {}
+
+    public func copy(_ text: String) throws {
+        let board = NSPasteboard.general
+        board.clearContents()
+        guard board.setString(text, forType: .string) else {
+            throw InserterError.pasteboardWriteFailed
+        }
+    }
+}
+
 struct PasteboardSaver {
     typealias Item = (type: NSPasteboard.PasteboardType, data: Data)
 
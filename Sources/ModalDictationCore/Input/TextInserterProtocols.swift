@@ -1,7 +1,14 @@
 public protocol TextInserting: Sendable {
+    // True when inserted text lands at the cursor of the focused app, where it can later be backspaced over.
+    var typesIntoFocusedApp: Bool { get }
+
     func insert(_ text: String) async throws
 }
 
+public protocol ClipboardWriting: Sendable {
+    func copy(_ text: String) throws
+}
+
 public protocol PasteStrategy {
     func paste() throws
 }
@@ -1,4 +1,5 @@
 @preconcurrency import AVFAudio
+import FluidAudio
 
 public struct CommandSession: Sendable {
     private let runner: SessionRunner
@@ -7,6 +8,9 @@ public struct CommandSession: Sendable {
     private let emitter: any KeystrokeEmitting
     private let commandsConfig: CommandsConfig
     private let triggerMatcher: VoiceTriggerMatcher
+    private let historyHandler: HistoryActionHandler?
+    private let observer: (any CommandEventObserving)?
+    private let profileSwitcher: ProfileSwitcher?
 
     public init(
         engine: any AudioCapturing,
@@ -14,6 +18,9 @@ public struct CommandSession: Sendable {
         matcher: CommandMatcher,
         emitter: any KeystrokeEmitting = LiveKeystrokeEmitter(),
         commandsConfig: CommandsConfig,
+        historyHandler: HistoryActionHandler? = nil,
+        observer: (any CommandEventObserving)? = nil,
+        profileSwitcher: ProfileSwitcher? = nil,
         deviceUID: String? = nil
     ) {
         self.runner = SessionRunner(engine: engine, deviceUID: deviceUID)
@@ -22,6 +29,9 @@ public struct CommandSession: Sendable {
         self.emitter = emitter
         self.commandsConfig = commandsConfig
         self.triggerMatcher = VoiceTriggerMatcher(actions: commandsConfig.actions)
+        self.historyHandler = historyHandler
+        self.observer = observer
+        self.profileSwitcher = profileSwitcher
     }
 
     public func run() async throws -> SessionEvent {
@@ -41,11 +51,58 @@ public struct CommandSession: Sendable {
 
         return try await runner.processResults(
             results,
-            triggerMatcher: triggerMatcher
+            triggerMatcher: triggerMatcher,
+            onTrigger: { trigger, result in
+                // The caller acts on the trigger once the session ends; it is reported here so observers see it.
+                await observer?.commandExecuted(
+                    CommandEvent(
+                        command: .action(trigger.rawValue),
+                        phrase: result.text,
+                        confidence: result.confidence,
+                        duration: .zero
+                    )
+                )
+            }
         ) { result in
             for command in matcher.match(result.text) {
+                try await execute(command, for: result)
+            }
+        }
+    }
+
+    private func execute(_ command: MatchedCommand, for result: ASRResult) async throws {
+        let clock = ContinuousClock()
+        let start = clock.now
+        do {
+            var handled = try await historyHandler?.handle(command) ?? false
+            if !handled {
+                handled = try await profileSwitcher?.handle(command) ?? false
+            }
+            // The emitter only performs keystrokes, so an action nothing claimed is reported as unhandled.
+            if !handled, case .keystroke = command {
                 try emitter.emit(command: command)
+                handled = true
             }
+            await observer?.commandExecuted(
+                CommandEvent(
+                    command: command,
+                    phrase: result.text,
+                    confidence: result.confidence,
+                    handled: handled,
+                    duration: clock.now - start
+                )
+            )
+        } catch {
+            await observer?.commandExecuted(
+                CommandEvent(
+                    command: command,
+                    phrase: result.text,
+                    confidence: result.confidence,
+                    failure: "\(error)",
+                    duration: clock.now - start
+                )
+            )
+            throw error
         }
     }
 
@@ -1,22 +1,36 @@
 @preconcurrency import AVFAudio
+import Foundation
 
 public struct DictationSession: Sendable {
     private let runner: SessionRunner
     private let recognizer: any SpeechRecognizing
     private let inserter: any TextInserting
     private let triggerMatcher: VoiceTriggerMatcher
+    private let history: TranscriptHistory?
+    private let formatter: (any TranscriptFormatting)?
+    private let historyHandler: HistoryActionHandler?
+    private let scratchPhrases: Set<String>
 
     public init(
         engine: any AudioCapturing,
         recognizer: any SpeechRecognizing,
         inserter: any TextInserting = PasteboardTextInserter(),
         actions: [String: String],
+        history: TranscriptHistory? = nil,
+        formatter: (any TranscriptFormatting)? = nil,
+        historyHandler: HistoryActionHandler? = nil,
         deviceUID: String? = nil
     ) {
         self.runner = SessionRunner(engine: engine, deviceUID: deviceUID)
         self.recognizer = recognizer
         self.inserter = inserter
         self.triggerMatcher = VoiceTriggerMatcher(actions: actions)
+        self.history = history
+        self.formatter = formatter
+        self.historyHandler = historyHandler
+        self.scratchPhrases = Set(
+            actions.filter { $0.value == HistoryAction.scratch.rawValue }.keys.map(Self.normalized)
+        )
     }
 
     public func run() async throws -> SessionEvent {
@@ -35,8 +49,29 @@ public struct DictationSession: Sendable {
             results,
             triggerMatcher: triggerMatcher
         ) { result in
-            try await inserter.insert(result.text)
+            // Only a whole utterance counts, so "scratch that" inside a sentence is still dictated.
+            if let historyHandler, scratchPhrases.contains(Self.normalized(result.text)) {
+                _ = try await historyHandler.handle(.action(HistoryAction.scratch.rawValue))
+                return
+            }
+            do {
+                if let historyHandler, try await historyHandler.correct(result.text) {
+                    return
+                }
+                let text = await formatter?.format(result.text) ?? result.text
+                try await inserter.insert(text)
+                // Scratch and corrections backspace over the last entry, so only text typed at the cursor is recorded.
+                if inserter.typesIntoFocusedApp {
+                    await history?.record(text)
+                }
+            } catch is FocusGuardError {
+                // Already reported to the user; nothing landed, so there is nothing to record.
+            }
         }
     }
 
+    private static func normalized(_ text: String) -> String {
+        text.lowercased().trimmingCharacters(in: .whitespacesAndNewlines.union(.punctuationCharacters))
+    }
+
 }
@@ -1,13 +1,15 @@
-public enum Mode: Sendable, Equatable {
+public enum Mode: String, Sendable, Equatable {
     case sleeping
     case dictation
     case command
+    // Entered only by voice, left only by a hotkey, so a misheard phrase can't reopen the mic.
+    case muted
 
     internal var startEffect: SideEffect? {
         switch self {
         case .dictation: .startDictation
         case .command: .startCommands
-        case .sleeping: nil
+        case .sleeping, .muted: nil
         }
     }
 
@@ -15,7 +17,7 @@ public enum Mode: Sendable, Equatable {
         switch self {
         case .dictation: .stopDictation
         case .command: .stopCommands
-        case .sleeping: nil
+        case .sleeping, .muted: nil
         }
     }
 }
@@ -8,6 +8,7 @@ public enum VoiceTrigger: String, Sendable, Equatable, CaseIterable {
     case wakeUp = "app:wake"
     case dictationMode = "mode:dictation"
     case commandMode = "mode:command"
+    case mute = "app:mute"
 }
 
 public enum ModeEvent: Sendable, Equatable {
@@ -13,62 +13,99 @@ public struct ModeStateMachine: Sendable, Equatable {
 
         // Already dictating — hold has nothing to restore to, so skip setting modeBeforeHold.
         case (.dictation, .hotkeyPress(.dictationHold)):
-            return ModeTransition(state: state, sideEffects: [])
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
+
+        // Only sleep_toggle leaves mute, so holding the dictation key must not reopen the mic.
+        case (.muted, .hotkeyPress(.dictationHold)):
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
 
         case (_, .hotkeyPress(.dictationHold)):
-            return enterHold(from: state)
+            return enterHold(from: state, on: event)
 
         case (.dictation, .hotkeyRelease(.dictationHold)):
             guard let restoreMode = modeBeforeHold else {
-                return ModeTransition(state: state, sideEffects: [])
+                return ModeTransition(state: state, sideEffects: [], trigger: event)
             }
             modeBeforeHold = nil
-            return enter(restoreMode)
+            return enter(restoreMode, on: event)
 
         case (_, .hotkeyRelease(.dictationHold)):
             modeBeforeHold = nil
-            return ModeTransition(state: state, sideEffects: [])
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
+
+        case (_, .voiceTrigger(.mute)):
+            return enter(.muted, on: event)
+
+        // A hold owns the mic until release; other requests only change where the release lands.
+        case let (.dictation, .voiceTrigger(trigger)) where modeBeforeHold != nil:
+            return retargetHold(for: trigger, on: event)
+
+        case (.dictation, .hotkeyPress(.sleepToggle)) where modeBeforeHold != nil:
+            modeBeforeHold = .sleeping
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
+
+        case (.dictation, .autoSleepFired) where modeBeforeHold != nil:
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
+
+        case (.muted, .hotkeyPress(.sleepToggle)):
+            return enter(lastActiveMode, on: event)
+
+        case (.muted, .autoSleepFired),
+             (.muted, .voiceTrigger):
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
 
         case (.sleeping, .hotkeyPress(.sleepToggle)):
-            return enter(lastActiveMode)
+            return enter(lastActiveMode, on: event)
 
         case (.sleeping, .voiceTrigger(.wakeUp)),
              (.sleeping, .voiceTrigger(.dictationMode)):
-            return enter(.dictation)
+            return enter(.dictation, on: event)
 
         case (.sleeping, .voiceTrigger(.commandMode)):
-            return enter(.command)
+            return enter(.command, on: event)
 
         case (_, .hotkeyPress(.sleepToggle)),
              (_, .autoSleepFired),
              (_, .voiceTrigger(.sleep)):
-            return enter(.sleeping)
+            return enter(.sleeping, on: event)
 
         case (.dictation, .voiceTrigger(.commandMode)):
-            return enter(.command)
+            return enter(.command, on: event)
 
         case (.command, .voiceTrigger(.dictationMode)):
-            return enter(.dictation)
+            return enter(.dictation, on: event)
 
         default:
-            return ModeTransition(state: state, sideEffects: [])
+            return ModeTransition(state: state, sideEffects: [], trigger: event)
         }
     }
 
     // Bypasses enter() to avoid updating lastActiveMode — hold is temporary.
-    private mutating func enterHold(from priorMode: Mode) -> ModeTransition {
+    private mutating func enterHold(from priorMode: Mode, on event: ModeEvent) -> ModeTransition {
         modeBeforeHold = priorMode
         let effects = Self.sideEffects(from: state, to: .dictation)
         state = .dictation
-        return ModeTransition(state: .dictation, sideEffects: effects)
+        return ModeTransition(state: .dictation, sideEffects: effects, trigger: event)
+    }
+
+    // The spoken trigger already ended the held dictation session, so it is started again to keep the hold live.
+    private mutating func retargetHold(for trigger: VoiceTrigger, on event: ModeEvent) -> ModeTransition {
+        switch trigger {
+        case .sleep: modeBeforeHold = .sleeping
+        case .dictationMode: modeBeforeHold = .dictation
+        case .commandMode: modeBeforeHold = .command
+        case .wakeUp where modeBeforeHold == .sleeping: modeBeforeHold = lastActiveMode
+        case .wakeUp, .mute: break
+        }
+        return ModeTransition(state: state, sideEffects: [.startDictation], trigger: event)
     }
 
-    private mutating func enter(_ newMode: Mode) -> ModeTransition {
+    private mutating func enter(_ newMode: Mode, on event: ModeEvent) -> ModeTransition {
         let effects = Self.sideEffects(from: state, to: newMode)
         modeBeforeHold = nil
         state = newMode
-        if newMode != .sleeping { lastActiveMode = newMode }
-        return ModeTransition(state: newMode, sideEffects: effects)
+        if newMode != .sleeping && newMode != .muted { lastActiveMode = newMode }
+        return ModeTransition(state: newMode, sideEffects: effects, trigger: event)
     }
 
     private static func sideEffects(from oldMode: Mode, to newMode: Mode) -> [SideEffect] {
@@ -8,4 +8,6 @@ public enum SideEffect: Sendable, Equatable {
 public struct ModeTransition: Sendable, Equatable {
     public let state: Mode
     public let sideEffects: [SideEffect]
+    // Lets observers tell a spoken "go to sleep" apart from the inactivity timer or the hotkey.
+    public let trigger: ModeEvent
 }
@@ -23,10 +23,12 @@ struct SessionRunner {
     func processResults(
         _ results: AsyncStream<ASRResult>,
         triggerMatcher: VoiceTriggerMatcher,
+        onTrigger: (VoiceTrigger, ASRResult) async -> Void = { _, _ in },
         onResult: (ASRResult) async throws -> Void
     ) async throws -> SessionEvent {
         for await result in results {
             if let trigger = triggerMatcher.match(result.text) {
+                await onTrigger(trigger, result)
                 return .voiceTrigger(trigger)
             }
             try await onResult(result)
@@ -6,11 +6,12 @@ public struct VoiceTriggerMatcher: Sendable {
     private let sortedPhrases: [(phrase: String, trigger: VoiceTrigger)]
 
     public init(actions: [String: String]) {
+        // Mute outranks every other trigger; otherwise the longest phrase wins.
         sortedPhrases = actions
-            .sorted { $0.key.count > $1.key.count }
             .compactMap { (phrase, action) in
                 Self.actionToTrigger[action].map { (phrase.lowercased(), $0) }
             }
+            .sorted { ($0.trigger == .mute ? 1 : 0, $0.phrase.count) > ($1.trigger == .mute ? 1 : 0, $1.phrase.count) }
     }
 
     public func match(_ text: String) -> VoiceTrigger? {
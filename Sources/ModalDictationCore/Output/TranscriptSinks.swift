@@ -0,0 +1,154 @@
+import Foundation
+import os
+
+public enum TranscriptSinkError: Error, Equatable {
+    case fileOpenFailed(String)
+    case commandFailed(command: String, status: Int32)
+}
+
+public enum TranscriptSinkKind: String, Sendable, Equatable, CaseIterable {
+    case paste
+    case file
+    case stdout
+    case command
+}
+
+public enum TranscriptSinks {
+
+    public static func make(from config: OutputConfig) throws -> any TextInserting {
+        try validate(config)
+        let sinks = (config.sinks ?? [.paste]).map { kind -> any TextInserting in
+            switch kind {
+            case .paste: FocusGuardedTextInserter(
+                inner: PasteboardTextInserter(),
+                allowlist: config.appAllowlist,
+                denylist: config.appDenylist
+            )
+            case .stdout: StandardOutputTranscriptSink()
+            case .file: FileTranscriptSink(path: config.filePath ?? "")
+            case .command: CommandTranscriptSink(command: config.command ?? "")
+            }
+        }
+        if sinks.count == 1 { return sinks[0] }
+        return FanOutTextInserter(sinks: sinks)
+    }
+
+    // A sink that can't run would silently discard every transcript, so a misconfigured list is a config error.
+    static func validate(_ config: OutputConfig) throws {
+        guard let kinds = config.sinks else { return }
+        if kinds.isEmpty {
+            throw ConfigError.invalidValue(key: "output.sinks", detail: "must list at least one sink")
+        }
+        if kinds.contains(.file), config.filePath?.isEmpty ?? true {
+            throw ConfigError.invalidValue(key: "output.file_path", detail: "required by the \"file\" sink")
+        }
+        if kinds.contains(.command), config.command?.isEmpty ?? true {
+            throw ConfigError.invalidValue(key: "output.command", detail: "required by the \"command\" sink")
+        }
+    }
+}
+
+public struct FanOutTextInserter: TextInserting {
+    private static let logger = Logger(subsystem: "ModalDictation", category: "FanOutTextInserter")
+
+    private let sinks: [any TextInserting]
+
+    public init(sinks: [any TextInserting]) {
+        self.sinks = sinks
+    }
+
+    public var typesIntoFocusedApp: Bool { sinks.contains { $0.typesIntoFocusedApp } }
+
+    // Every sink gets the text even if an earlier one fails; the first failure is rethrown afterwards.
+    public func insert(_ text: String) async throws {
+        var firstError: (any Error)?
+        for sink in sinks {
+            do {
+                try await sink.insert(text)
+            } catch {
+                Self.logger.error("Transcript sink \(String(describing: type(of: sink))) failed: \(error)")
+                firstError = firstError ?? error
+            }
+        }
+        if let firstError { throw firstError }
+    }
+}
+
+public struct FileTranscriptSink: TextInserting {
+    public let typesIntoFocusedApp = false
+    private let path: String
+
+    public init(path: String) {
+        self.path = (path as NSString).expandingTildeInPath
+    }
+
+    public func insert(_ text: String) async throws {
+        let fm = FileManager.default
+        if !fm.fileExists(atPath: path) {
+            fm.createFile(atPath: path, contents: nil)
+        }
+        guard let handle = FileHandle(forWritingAtPath: path) else {
+            throw TranscriptSinkError.fileOpenFailed(path)
+        }
+        defer { try? handle.close() }
+        try handle.seekToEnd()
+        try handle.write(contentsOf: Data((text + "\n").utf8))
+    }
+}
+
+public struct StandardOutputTranscriptSink: TextInserting {
+    public let typesIntoFocusedApp = false
+
+    public init() {}
+
+    public func insert(_ text: String) async throws {
+        try FileHandle.standardOutput.write(contentsOf: Data((text + "\n").utf8))
+    }
+}
+
+// Runs the command through /bin/sh with the transcript on stdin, e.g. `pbcopy` or `tee -a notes.md`.
+public struct CommandTranscriptSink: TextInserting {
+    public let typesIntoFocusedApp = false
+    private let command: String
+
+    public init(command: String) {
+        self.command = command
+    }
+
+    public func insert(_ text: String) async throws {
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: "/bin/sh")
+        process.arguments = ["-c", command]
+        let input = Pipe()
+        process.standardInput = input
+        let writer = input.fileHandleForWriting
+        // A command that exits without reading stdin makes the write fail with EPIPE instead of raising SIGPIPE.
+        _ = fcntl(writer.fileDescriptor, F_SETNOSIGPIPE, 1)
+
+        let command = command
+        try await withCheckedThrowingContinuation { (continuation: CheckedContinuation<Void, any Error>) in
+            process.terminationHandler = { process in
+                if process.terminationStatus == 0 {
+                    continuation.resume()
+                } else {
+                    continuation.resume(throwing: TranscriptSinkError.commandFailed(
+                        command: command, status: process.terminationStatus
+                    ))
+                }
+            }
+            do {
+                try process.run()
+            } catch {
+                try? writer.close()
+                continuation.resume(throwing: error)
+                return
+            }
+            // Written after launch and off the cooperative pool, so text larger than the pipe buffer drains
+            // into the running command rather than blocking before it starts.
+            DispatchQueue.global(qos: .utility).async {
+                try? writer.write(contentsOf: Data(text.utf8))
+                try? writer.close()
+            }
+        }
+    }
+}
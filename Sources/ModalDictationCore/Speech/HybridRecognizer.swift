@@ -9,19 +9,24 @@ public actor HybridRecognizer {
     private let streamingTranscriber: any StreamingTranscriber
     private let batchTranscriber: any BatchTranscriber
     private let converter: AudioConverter
+    private let maxUtteranceSamples: Int?
 
     private var sampleBuffer: [Float] = []
+    private var continuesForcedUtterance = false
     private var processingTask: Task<Void, Never>?
     private var eouTask: Task<Void, Never>?
     private var resultContinuation: AsyncStream<ASRResult>.Continuation?
 
     public init(
         streamingTranscriber: any StreamingTranscriber,
-        batchTranscriber: any BatchTranscriber
+        batchTranscriber: any BatchTranscriber,
+        maxUtteranceSeconds: Double? = nil
     ) {
         self.streamingTranscriber = streamingTranscriber
         self.batchTranscriber = batchTranscriber
         self.converter = AudioConverter()
+        // Below the batch minimum every forced chunk would be padded, so never force sooner than that.
+        self.maxUtteranceSamples = maxUtteranceSeconds.map { max(Int($0 * Double(Self.sampleRate)), Self.minimumSamples) }
     }
 
     // Two-task pipeline: processingTask accumulates samples and feeds the streaming transcriber.
@@ -67,15 +72,31 @@ public actor HybridRecognizer {
         guard let samples = try? converter.resampleBuffer(buffer) else { return }
         sampleBuffer.append(contentsOf: samples)
         _ = try? await streamingTranscriber.process(audioBuffer: buffer)
+
+        // No EOU in sight (e.g. constant background noise) — finalize now instead of buffering forever.
+        if let maxUtteranceSamples, sampleBuffer.count >= maxUtteranceSamples {
+            Self.logger.warning("Utterance exceeded \(maxUtteranceSamples) samples without EOU — forcing transcription")
+            await streamingTranscriber.reset()
+            await transcribeAccumulated()
+            continuesForcedUtterance = true
+        }
     }
 
-    private static let minimumSamples = 16_000 // 1 second at 16 kHz
+    private static let sampleRate = 16_000
+    private static let minimumSamples = sampleRate // 1 second at 16 kHz
 
+    // Short audio on its own is noise and is skipped. The tail of a force-finalized utterance is real speech,
+    // so it is padded with silence up to the batch minimum instead.
     private func transcribeAccumulated() async {
-        let samples = sampleBuffer
+        var samples = sampleBuffer
         sampleBuffer.removeAll(keepingCapacity: true)
+        let isTail = continuesForcedUtterance
+        continuesForcedUtterance = false
 
-        guard samples.count >= Self.minimumSamples else { return }
+        if samples.count < Self.minimumSamples {
+            guard isTail, !samples.isEmpty else { return }
+            samples += [Float](repeating: 0, count: Self.minimumSamples - samples.count)
+        }
 
         do {
             let result = try await batchTranscriber.transcribe(samples)
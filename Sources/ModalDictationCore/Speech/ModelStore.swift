@@ -88,6 +88,12 @@ public actor ModelStore {
         }
     }
 
+    // EOU stays resident — it is small and needed to start any session quickly.
+    public func unloadIdleModels() {
+        tdt = ModelSlot()
+        ctc = ModelSlot()
+    }
+
     public func getTDTModels() throws -> AsrModels {
         guard let models = tdt.value else {
             throw ModelStoreError.modelsNotLoaded("TDT models not loaded — call loadTDT() first")
@@ -114,12 +120,13 @@ extension ModelStore: ModelProviding {
     public func makeBatchTranscriber(
         commandsConfig: CommandsConfig? = nil
     ) async throws -> any BatchTranscriber {
-        let models = try getTDTModels()
+        // Load rather than get — models may have been unloaded while idle.
+        let models = try await loadTDT()
         let manager = AsrManager()
         try await manager.initialize(models: models)
 
         if let commandsConfig {
-            let ctc = try getCTCModels()
+            let ctc = try await loadCTC()
             let vocabulary = VocabularyBuilder.build(from: commandsConfig)
             try await manager.configureVocabularyBoosting(
                 vocabulary: vocabulary,
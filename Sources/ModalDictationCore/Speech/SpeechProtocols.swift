@@ -29,4 +29,5 @@ public protocol ModelProviding: Sendable {
         commandsConfig: CommandsConfig?
     ) async throws -> any BatchTranscriber
     func getEOUManager() async throws -> any StreamingTranscriber
+    func unloadIdleModels() async
 }
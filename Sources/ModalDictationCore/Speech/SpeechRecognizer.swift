@@ -22,12 +22,29 @@ public actor SpeechRecognizer: SpeechRecognizing {
     }
 
     private let modelStore: any ModelProviding
+    private let maxUtteranceSeconds: Double?
+    private let unloadAfterIdle: Duration?
     public private(set) var mode: Mode = .idle
 
     private var hybridRecognizer: HybridRecognizer?
+    private var unloadTask: Task<Void, Never>?
 
-    public init(modelStore: any ModelProviding) {
+    public init(
+        modelStore: any ModelProviding,
+        maxUtteranceSeconds: Double? = nil,
+        unloadAfterIdle: Duration? = nil
+    ) {
         self.modelStore = modelStore
+        self.maxUtteranceSeconds = maxUtteranceSeconds
+        self.unloadAfterIdle = unloadAfterIdle
+    }
+
+    public init(modelStore: any ModelProviding, config: SpeechConfig) {
+        self.init(
+            modelStore: modelStore,
+            maxUtteranceSeconds: config.maxUtteranceSeconds,
+            unloadAfterIdle: config.unloadModelsAfterIdleMinutes.map { .seconds($0 * 60) }
+        )
     }
 
     public func initialize() async throws {
@@ -55,10 +72,16 @@ public actor SpeechRecognizer: SpeechRecognizing {
         guard self.mode == .idle else {
             throw SpeechRecognizerError.alreadyActive("Cannot start \(mode) while \(self.mode) is active")
         }
+        unloadTask?.cancel()
+        unloadTask = nil
 
         let streaming = try await modelStore.getEOUManager()
         let batch = try await modelStore.makeBatchTranscriber(commandsConfig: commandsConfig)
-        let recognizer = HybridRecognizer(streamingTranscriber: streaming, batchTranscriber: batch)
+        let recognizer = HybridRecognizer(
+            streamingTranscriber: streaming,
+            batchTranscriber: batch,
+            maxUtteranceSeconds: maxUtteranceSeconds
+        )
         hybridRecognizer = recognizer
         self.mode = mode
 
@@ -69,5 +92,19 @@ public actor SpeechRecognizer: SpeechRecognizing {
         await hybridRecognizer?.stop()
         hybridRecognizer = nil
         mode = .idle
+        scheduleUnload()
+    }
+
+    // Trades first-utterance latency after a long sleep for the memory held by the batch models.
+    // A mode switch starts the next session right away and cancels the timer, so it only runs out
+    // once the app has been asleep (or muted) for the whole period.
+    private func scheduleUnload() {
+        guard let unloadAfterIdle else { return }
+        unloadTask?.cancel()
+        unloadTask = Task { [modelStore] in
+            try? await Task.sleep(for: unloadAfterIdle)
+            guard !Task.isCancelled else { return }
+            await modelStore.unloadIdleModels()
+        }
     }
 }
@@ -0,0 +1,71 @@
+import Foundation
+import Testing
+@testable import ModalDictationCore
+
+@Suite("CommandAuditLog")
+struct CommandAuditLogTests {
+
+    private static func readEntries(_ url: URL) throws -> [CommandAuditLog.Entry] {
+        try String(contentsOf: url, encoding: .utf8)
+            .split(separator: "\n")
+            .map { line in
+                let decoder = JSONDecoder()
+                decoder.keyDecodingStrategy = .convertFromSnakeCase
+                return try decoder.decode(CommandAuditLog.Entry.self, from: Data(line.utf8))
+            }
+    }
+
+    @Test func test_commandExecuted_appendsJSONLine() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("audit.log")
+        let log = CommandAuditLog(path: file.path)
+
+        await log.commandExecuted(CommandEvent(
+            command: .keystroke(key: "c", modifiers: ["cmd"], repeat: 1),
+            phrase: "command charlie",
+            confidence: 0.8,
+            duration: .milliseconds(12)
+        ))
+        await log.commandExecuted(CommandEvent(
+            command: .action("history:inject", argument: 2),
+            phrase: "inject item two",
+            failure: "pasteboardWriteFailed",
+            duration: .milliseconds(3)
+        ))
+
+        let entries = try Self.readEntries(file)
+        #expect(entries.map(\.command) == ["cmd+c", "history:inject 2"])
+        #expect(entries.map(\.succeeded) == [true, false])
+        #expect(entries[0].confidence == 0.8)
+        #expect(entries[0].durationMs == 12)
+        #expect(entries[1].failure == "pasteboardWriteFailed")
+    }
+
+    @Test func test_commandExecuted_unhandledActionNotRecordedAsSuccess() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("audit.log")
+        let log = CommandAuditLog(path: file.path)
+
+        await log.commandExecuted(CommandEvent(
+            command: .action("history:read_back"), phrase: "read that back", handled: false, duration: .zero
+        ))
+
+        let entries = try Self.readEntries(file)
+        #expect(entries.map(\.handled) == [false])
+        #expect(entries.map(\.succeeded) == [false])
+    }
+
+    @Test func test_commandExecuted_rotatesWhenFull() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("audit.log")
+        let log = CommandAuditLog(path: file.path, maxBytes: 1)
+        let event = CommandEvent(command: .action("app:sleep"), phrase: "go to sleep", duration: .zero)
+
+        await log.commandExecuted(event)
+        await log.commandExecuted(event)
+
+        #expect(try Self.readEntries(file).count == 1)
+        #expect(try Self.readEntries(URL(fileURLWithPath: file.path + ".1")).count == 1)
+    }
+
+    @Test func test_make_disabledByDefault() {
+        #expect(CommandAuditLog.make(from: PrivacyConfig()) == nil)
+    }
+}
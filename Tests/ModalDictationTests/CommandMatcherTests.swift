@@ -71,6 +71,28 @@ struct CommandMatcherTests {
         #expect(result == [.action("selectAll")])
     }
 
+    @Test func test_match_actionWithTrailingNumber_capturesArgument() {
+        let matcher = CommandMatcher(commands: .fixture(
+            actions: ["inject item": "history:inject"],
+            keys: ["three": "3"]
+        ))
+
+        let result = matcher.match("inject item three")
+
+        #expect(result == [.action("history:inject", argument: 3)])
+    }
+
+    @Test func test_match_numberAfterPlainAction_matchedAsKey() {
+        let matcher = CommandMatcher(commands: .fixture(
+            actions: ["select all": "selectAll"],
+            keys: ["one": "1"]
+        ))
+
+        let result = matcher.match("select all one")
+
+        #expect(result == [.action("selectAll"), .keystroke(key: "1", modifiers: [], repeat: 1)])
+    }
+
     @Test func test_match_repeatWithNumberWord() {
         let matcher = CommandMatcher(commands: .fixture(keys: ["down": "down"]))
 
@@ -95,6 +117,29 @@ struct CommandMatcherTests {
         #expect(result == [.keystroke(key: "down", modifiers: [], repeat: 200)])
     }
 
+    @Test func test_match_repeatWithCompoundNumber() {
+        let matcher = CommandMatcher(commands: .fixture(keys: ["down": "down"]))
+
+        let result = matcher.match("down twenty five times")
+
+        #expect(result == [.keystroke(key: "down", modifiers: [], repeat: 25)])
+    }
+
+    @Test func test_match_repeatWithHundredAndTens() {
+        let matcher = CommandMatcher(commands: .fixture(keys: ["down": "down"]))
+
+        let result = matcher.match("down one hundred twenty times")
+
+        #expect(result == [.keystroke(key: "down", modifiers: [], repeat: 120)])
+    }
+
+    @Test func test_match_zeroTimes_notARepeatCount() {
+        let matcher = CommandMatcher(commands: .fixture(keys: ["down": "down"]))
+
+        #expect(matcher.match("down zero times") == [.keystroke(key: "down", modifiers: [], repeat: 1)])
+        #expect(matcher.match("down 0 times") == [.keystroke(key: "down", modifiers: [], repeat: 1)])
+    }
+
     @Test func test_match_fuzzyFallback_matchesCloseWord() {
         let matcher = CommandMatcher(commands: .fixture(keys: ["adam": "a"]))
 
@@ -6,7 +6,9 @@ import FluidAudio
 struct CommandSessionTests {
 
     private static func makeSUT(
-        commandsConfig: CommandsConfig = CommandsConfig()
+        commandsConfig: CommandsConfig = CommandsConfig(),
+        historyHandler: HistoryActionHandler? = nil,
+        observer: (any CommandEventObserving)? = nil
     ) -> (session: CommandSession, engine: MockAudioEngine, recognizer: MockSpeechRecognizer, emitter: MockKeystrokeEmitter) {
         let engine = MockAudioEngine()
         let recognizer = MockSpeechRecognizer()
@@ -16,7 +18,9 @@ struct CommandSessionTests {
             recognizer: recognizer,
             matcher: CommandMatcher(commands: commandsConfig),
             emitter: emitter,
-            commandsConfig: commandsConfig
+            commandsConfig: commandsConfig,
+            historyHandler: historyHandler,
+            observer: observer
         )
         return (session, engine, recognizer, emitter)
     }
@@ -36,4 +40,81 @@ struct CommandSessionTests {
         #expect(emitter.emittedCommands == [.keystroke(key: "a", modifiers: [], repeat: 1)])
     }
 
+    @Test
+    func test_historyAction_insertsTranscriptInsteadOfEmitting() async throws {
+        let history = TranscriptHistory()
+        await history.record("earlier note")
+        let inserter = MockTextInserter()
+        let config = CommandsConfig(actions: ["paste last transcript": "history:paste_last"])
+        let (session, _, recognizer, emitter) = Self.makeSUT(
+            commandsConfig: config,
+            historyHandler: HistoryActionHandler(history: history, inserter: inserter)
+        )
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "paste last transcript"))
+        continuation.finish()
+
+        let event = try await session.run()
+        #expect(event == .completed)
+        #expect(await inserter.insertedTexts == ["earlier note"])
+        #expect(emitter.emittedCommands.isEmpty)
+    }
+
+    @Test
+    func test_executedCommands_reportedToObserver() async throws {
+        let observer = MockCommandEventObserver()
+        let config = CommandsConfig(keys: ["alpha": "a", "bravo": "b"])
+        let (session, _, recognizer, _) = Self.makeSUT(commandsConfig: config, observer: observer)
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "alpha bravo"))
+        continuation.finish()
+
+        _ = try await session.run()
+        let events = await observer.events
+        #expect(events.map(\.command) == [
+            .keystroke(key: "a", modifiers: [], repeat: 1),
+            .keystroke(key: "b", modifiers: [], repeat: 1),
+        ])
+        #expect(events.allSatisfy { $0.phrase == "alpha bravo" && $0.succeeded })
+    }
+
+    @Test
+    func test_voiceTrigger_reportedToObserver() async throws {
+        let observer = MockCommandEventObserver()
+        let config = CommandsConfig(actions: ["go to sleep": "app:sleep"])
+        let (session, _, recognizer, _) = Self.makeSUT(commandsConfig: config, observer: observer)
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "go to sleep"))
+        continuation.finish()
+
+        let event = try await session.run()
+        #expect(event == .voiceTrigger(.sleep))
+        let events = await observer.events
+        #expect(events.map(\.command) == [.action("app:sleep")])
+        #expect(events.allSatisfy(\.succeeded))
+    }
+
+    @Test
+    func test_unclaimedAction_reportedAsUnhandled() async throws {
+        let observer = MockCommandEventObserver()
+        let config = CommandsConfig(actions: ["read that back": "history:read_back"])
+        let (session, _, recognizer, emitter) = Self.makeSUT(commandsConfig: config, observer: observer)
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "read that back"))
+        continuation.finish()
+
+        _ = try await session.run()
+        let events = await observer.events
+        #expect(events.map(\.handled) == [false])
+        #expect(events.map(\.succeeded) == [false])
+        #expect(emitter.emittedCommands.isEmpty)
+    }
 }
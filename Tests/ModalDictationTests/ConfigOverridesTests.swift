@@ -0,0 +1,109 @@
+import Foundation
+import Testing
+@testable import ModalDictationCore
+
+@Suite("ConfigOverrides")
+struct ConfigOverridesTests {
+
+    @Test func test_fromEnvironment_mapsDoubleUnderscoreToTables() {
+        let overrides = ConfigOverrides.fromEnvironment([
+            "MODAL_DICTATION_SPEECH__MAX_UTTERANCE_SECONDS": "20",
+            "MODAL_DICTATION_FEEDBACK__TTS__RATE": "0.7",
+            "HOME": "/Users/me",
+        ])
+
+        #expect(overrides == ["speech.max_utterance_seconds": "20", "feedback.tts.rate": "0.7"])
+    }
+
+    @Test func test_fromEnvironment_unknownKeySkipped() {
+        let overrides = ConfigOverrides.fromEnvironment([
+            "MODAL_DICTATION_SPEECH__TIMOUT": "2.0",
+            "MODAL_DICTATION_HOME": "/opt/modal-dictation",
+            "MODAL_DICTATION_SPEECH__TIMEOUT": "2.0",
+        ])
+
+        #expect(overrides == ["speech.timeout": "2.0"])
+    }
+
+    @Test func test_read_strayEnvironmentVariable_stillLoads() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("[speech]\ntimeout = 1.0", to: file)
+
+        let config = try ConfigReader.read(from: file.path, overrides: ConfigOverrides.resolve(
+            environment: ["MODAL_DICTATION_LOG_LEVEL": "debug"],
+            arguments: ["ModalDictation"]
+        ))
+
+        #expect(config.speech.timeout == 1.0)
+    }
+
+    @Test func test_resolve_argumentsWinOverEnvironment() {
+        let overrides = ConfigOverrides.resolve(
+            environment: ["MODAL_DICTATION_SPEECH__TIMEOUT": "1.0"],
+            arguments: ["ModalDictation", "--set", "speech.timeout=2.5", "--set", "mic.device_id=usb=1"]
+        )
+
+        #expect(overrides == ["speech.timeout": "2.5", "mic.device_id": "usb=1"])
+    }
+
+    @Test func test_read_appliesOverridesOverFile() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("[speech]\ntimeout = 1.0\n\n[mic]\ndevice_id = \"builtin\"", to: file)
+
+        let config = try ConfigReader.read(from: file.path, overrides: [
+            "speech.timeout": "2.5",
+            "mic.device_id": "usb",
+            "feedback.tts.rate": "0.7",
+            "output.sinks": "[\"stdout\"]",
+        ])
+
+        #expect(config.speech.timeout == 2.5)
+        #expect(config.mic.deviceID == "usb")
+        #expect(config.feedback.ttsRate == 0.7)
+        #expect(config.output.sinks == [.stdout])
+    }
+
+    @Test func test_read_overrideCoercedToTypeOfKey() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("", to: file)
+
+        let config = try ConfigReader.read(from: file.path, overrides: [
+            "mic.device_id": "123",
+            "speech.timeout": "2",
+            "history.capacity": "5",
+            "formatting.units": "false",
+        ])
+
+        #expect(config.mic.deviceID == "123")
+        #expect(config.speech.timeout == 2.0)
+        #expect(config.history.capacity == 5)
+        #expect(config.formatting.units == false)
+    }
+
+    @Test func test_read_overrideOfWrongType_throwsInvalidValue() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("", to: file)
+
+        #expect {
+            try ConfigReader.read(from: file.path, overrides: ["speech.timeout": "fast"])
+        } throws: { error in
+            guard case let ConfigError.invalidValue(key, _) = error else { return false }
+            return key == "speech.timeout"
+        }
+    }
+
+    @Test func test_read_overrideOfUnknownKey_throwsInvalidValue() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("", to: file)
+
+        #expect(throws: ConfigError.self) {
+            try ConfigReader.read(from: file.path, overrides: ["speech.timout": "2.0"])
+        }
+    }
+
+    @Test func test_removingOverrides_keepsPositionalArguments() {
+        let remaining = ConfigOverrides.removingOverrides(from: ["--set", "speech.timeout=2.0", "custom.toml", "--set", "a=b"])
+
+        #expect(remaining == ["custom.toml"])
+    }
+}
@@ -1,3 +1,4 @@
+import Foundation
 import Testing
 @testable import ModalDictationCore
 
@@ -53,6 +54,15 @@ struct ConfigReaderTests {
         }
     }
 
+    @Test func test_parse_invalidToml_reportsLocation() throws {
+        #expect {
+            try ConfigReader.parse("[mic]\ndevice_id = ")
+        } throws: { error in
+            guard case let ConfigError.syntaxError(line, _, _) = error else { return false }
+            return line == 2
+        }
+    }
+
     @Test func test_parse_emptyToml_returnsAllDefaults() throws {
         let config = try ConfigReader.parse("")
 
@@ -125,9 +135,323 @@ struct ConfigReaderTests {
         #expect(config.commands.keys["broken"] == nil)
     }
 
+    @Test func test_parse_maxUtteranceBelowOneSecond_throwsInvalidValue() throws {
+        #expect {
+            try ConfigReader.parse("[speech]\nmax_utterance_seconds = 0.5")
+        } throws: { error in
+            guard case let ConfigError.invalidValue(key, _) = error else { return false }
+            return key == "speech.max_utterance_seconds"
+        }
+    }
+
+    @Test func test_parse_nonPositiveUnloadMinutes_throwsInvalidValue() throws {
+        #expect(throws: ConfigError.self) {
+            try ConfigReader.parse("[speech]\nunload_models_after_idle_minutes = 0.0")
+        }
+    }
+
+    @Test func test_parse_historySection_readsCapacityAndClipboardFlag() throws {
+        let toml = """
+        [history]
+        capacity = 5
+        copy_to_clipboard = true
+        correction_phrase = "fix"
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.history.capacity == 5)
+        #expect(config.history.copyToClipboard == true)
+        #expect(config.history.correctionPhrase == "fix")
+    }
+
+    @Test func test_parse_commandSets_mergesOnlyEnabledSets() throws {
+        let toml = """
+        [commands]
+        command_sets = ["editing"]
+
+        [commands.keys]
+        "adam" = "a"
+
+        [command_sets.editing.keys]
+        "wipe" = "backspace"
+
+        [command_sets.git.actions]
+        "git status" = "git:status"
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.commands.keys == ["adam": "a", "wipe": "backspace"])
+        #expect(config.commands.actions.isEmpty)
+    }
+
+    @Test func test_parse_samePhraseInTwoSets_throwsConflict() throws {
+        let toml = """
+        [command_sets.editing.keys]
+        "wipe" = "backspace"
+
+        [command_sets.cleanup.actions]
+        "wipe" = "clear:all"
+        """
+
+        #expect {
+            try ConfigReader.parse(toml)
+        } throws: { error in
+            guard case ConfigError.commandConflict(let phrase, _) = error else { return false }
+            return phrase == "wipe"
+        }
+    }
+
+    @Test func test_parse_setPhraseAlsoInBaseCommands_throwsConflict() throws {
+        let toml = """
+        [commands.keys]
+        "wipe" = "backspace"
+
+        [command_sets.cleanup.actions]
+        "wipe" = "clear:all"
+        """
+
+        #expect {
+            try ConfigReader.parse(toml)
+        } throws: { error in
+            guard case ConfigError.commandConflict(let phrase, let sets) = error else { return false }
+            return phrase == "wipe" && sets == ["[commands]", "cleanup"]
+        }
+    }
+
+    @Test func test_parse_unknownEnabledSet_throws() throws {
+        let toml = """
+        [commands]
+        command_sets = ["missing"]
+        """
+
+        #expect(throws: ConfigError.self) {
+            try ConfigReader.parse(toml)
+        }
+    }
+
+    @Test func test_parse_outputSinks() throws {
+        let toml = """
+        [output]
+        sinks = ["paste", "file"]
+        file_path = "~/dictation.txt"
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.output.sinks == [.paste, .file])
+        #expect(config.output.filePath == "~/dictation.txt")
+    }
+
+    @Test func test_parse_outputSinks_unknownKind_throws() throws {
+        #expect {
+            try ConfigReader.parse("[output]\nsinks = [\"paste\", \"bogus\"]")
+        } throws: { error in
+            guard case let ConfigError.invalidValue(key, _) = error else { return false }
+            return key == "output.sinks"
+        }
+    }
+
+    @Test func test_parse_outputSinks_emptyList_throws() throws {
+        #expect(throws: ConfigError.self) {
+            try ConfigReader.parse("[output]\nsinks = []")
+        }
+    }
+
+    @Test func test_parse_fileSinkWithoutPath_throws() throws {
+        #expect {
+            try ConfigReader.parse("[output]\nsinks = [\"file\"]")
+        } throws: { error in
+            guard case let ConfigError.invalidValue(key, _) = error else { return false }
+            return key == "output.file_path"
+        }
+    }
+
+    @Test func test_parse_outputAppLists() throws {
+        let toml = """
+        [output]
+        app_allowlist = ["com.apple.TextEdit"]
+        app_denylist = ["com.microsoft.rdc.macos"]
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.output.appAllowlist == ["com.apple.TextEdit"])
+        #expect(config.output.appDenylist == ["com.microsoft.rdc.macos"])
+    }
+
+    @Test func test_parse_replacements_readsGlobalAndPerApp() throws {
+        let toml = """
+        [replacements]
+        "my email" = "me@example.com"
+
+        [apps."com.apple.mail".replacements]
+        "my signature" = "Best regards"
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.replacements.global == ["my email": "me@example.com"])
+        #expect(config.replacements.perApp == ["com.apple.mail": ["my signature": "Best regards"]])
+    }
+
+    @Test func test_parse_formatting() throws {
+        let toml = """
+        [formatting]
+        locale = "de_DE"
+        units = false
+        spoken_punctuation = true
+        capitalize = false
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.formatting == FormattingConfig(
+            locale: "de_DE", units: false, spokenPunctuation: true, capitalize: false
+        ))
+    }
+
+    @Test func test_parse_feedbackTTS() throws {
+        let toml = """
+        [feedback.tts]
+        rate = 0.6
+        voice = "en-GB"
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.feedback == FeedbackConfig(ttsRate: 0.6, ttsVoice: "en-GB"))
+    }
+
+    @Test func test_parse_startupMode() throws {
+        let config = try ConfigReader.parse("[startup]\nmode = \"dictation\"")
+
+        #expect(config.startup.mode == .dictation)
+    }
+
+    @Test(arguments: ["awake", "muted"])
+    func test_parse_startupMode_unsupported_throwsInvalidValue(mode: String) throws {
+        #expect {
+            try ConfigReader.parse("[startup]\nmode = \"\(mode)\"")
+        } throws: { error in
+            guard case ConfigError.invalidValue(let key, _) = error else { return false }
+            return key == "startup.mode"
+        }
+    }
+
+    @Test func test_parse_privacy() throws {
+        let toml = """
+        [privacy]
+        audit_commands = true
+        audit_file = "~/audit.log"
+        """
+
+        let config = try ConfigReader.parse(toml)
+
+        #expect(config.privacy == PrivacyConfig(auditCommands: true, auditFile: "~/audit.log"))
+    }
+
     @Test func test_read_nonexistentPath_throwsFileNotFound() throws {
         #expect(throws: ConfigError.self) {
             try ConfigReader.read(from: "/nonexistent/path.toml")
         }
     }
+
+    @Test func test_read_include_mergesCommandsWithMainFileWinning() throws {
+        let dir = try makeTemporaryDirectory()
+        try write("""
+        [commands.keys]
+        "adam" = "x"
+        "boy" = "b"
+        """, to: dir.appendingPathComponent("commands/letters.toml"))
+        let main = dir.appendingPathComponent("config.toml")
+        try write("""
+        include = ["commands/letters.toml"]
+
+        [commands.keys]
+        "adam" = "a"
+        """, to: main)
+
+        let config = try ConfigReader.read(from: main.path)
+
+        #expect(config.commands.keys == ["adam": "a", "boy": "b"])
+        #expect(config.includedFiles == [dir.appendingPathComponent("commands/letters.toml").standardizedFileURL.path])
+    }
+
+    @Test func test_read_includeCycle_throwsIncludeCycle() throws {
+        let dir = try makeTemporaryDirectory()
+        try write(#"include = ["b.toml"]"#, to: dir.appendingPathComponent("a.toml"))
+        try write(#"include = ["a.toml"]"#, to: dir.appendingPathComponent("b.toml"))
+
+        #expect {
+            try ConfigReader.read(from: dir.appendingPathComponent("a.toml").path)
+        } throws: { error in
+            guard case ConfigError.includeCycle = error else { return false }
+            return true
+        }
+    }
+
+    @Test func test_read_missingInclude_throwsFileNotFound() throws {
+        let dir = try makeTemporaryDirectory()
+        let main = dir.appendingPathComponent("config.toml")
+        try write(#"include = ["missing.toml"]"#, to: main)
+
+        #expect(throws: ConfigError.self) {
+            try ConfigReader.read(from: main.path)
+        }
+    }
+
+    @Test func test_diagnose_reportsUnknownKeysAndTypeMismatches() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("""
+        [speech]
+        timeout = "2"
+        timout = 2.0
+
+        [formatting]
+        capitalize = 1
+
+        [commands.keys]
+        "adam" = "a"
+
+        [profiles.meeting.speech]
+        timeout = 2
+        """, to: file)
+
+        let diagnostics = try ConfigReader.diagnose(fileAt: file.path)
+
+        #expect(Set(diagnostics) == [
+            .typeMismatch(key: "speech.timeout", expected: .number),
+            .unknownKey("speech.timout"),
+            .typeMismatch(key: "formatting.capitalize", expected: .boolean),
+            .typeMismatch(key: "profiles.meeting.speech.timeout", expected: .number),
+        ])
+    }
+
+    @Test func test_diagnose_validConfig_reportsNothing() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write("""
+        include = ["letters.toml"]
+
+        [speech]
+        timeout = 0.3
+
+        [output]
+        sinks = ["paste"]
+
+        [apps."com.apple.mail".replacements]
+        "my sig" = "Best"
+
+        [command_sets.git.actions]
+        "git status" = "mode:command"
+
+        [hotkeys.dictation_hold_device]
+        vendor_id = 0x1234
+        product_id = 0x5678
+        button = 1
+        """, to: file)
+
+        #expect(try ConfigReader.diagnose(fileAt: file.path).isEmpty)
+    }
 }
@@ -0,0 +1,125 @@
+import Foundation
+import Testing
+@testable import ModalDictationCore
+
+@Suite("ConfigWatcher")
+struct ConfigWatcherTests {
+
+    private actor ChangeCounter {
+        private(set) var count = 0
+        func increment() { count += 1 }
+    }
+
+    private static func startWatcher(
+        at file: URL
+    ) -> (watcher: ConfigWatcher, counter: ChangeCounter) {
+        let counter = ChangeCounter()
+        let watcher = ConfigWatcher(filePath: file.path, debounce: .milliseconds(100)) {
+            Task { await counter.increment() }
+        }
+        watcher.start()
+        return (watcher, counter)
+    }
+
+    @Test func test_vimStyleSave_reloadsOnce() async throws {
+        let dir = try makeTemporaryDirectory()
+        let config = dir.appendingPathComponent("config.toml")
+        try write("[mic]", to: config)
+        let (watcher, counter) = Self.startWatcher(at: config)
+        defer { watcher.stop() }
+
+        try write("swap", to: dir.appendingPathComponent(".config.toml.swp"))
+        try FileManager.default.moveItem(at: config, to: dir.appendingPathComponent("config.toml~"))
+        try write("[mic]\ndevice_id = \"usb\"", to: config)
+
+        try await waitUntil { await counter.count >= 1 }
+        try await Task.sleep(for: .milliseconds(300))
+        #expect(await counter.count == 1)
+    }
+
+    @Test func test_emacsStyleSave_reloadsOnce() async throws {
+        let dir = try makeTemporaryDirectory()
+        let config = dir.appendingPathComponent("config.toml")
+        try write("[mic]", to: config)
+        let (watcher, counter) = Self.startWatcher(at: config)
+        defer { watcher.stop() }
+
+        let autosave = dir.appendingPathComponent("#config.toml#")
+        try write("[mic]", to: autosave)
+        try FileManager.default.moveItem(at: config, to: dir.appendingPathComponent("config.toml~"))
+        try write("[mic]\ndevice_id = \"usb\"", to: config)
+        try FileManager.default.removeItem(at: autosave)
+
+        try await waitUntil { await counter.count >= 1 }
+        try await Task.sleep(for: .milliseconds(300))
+        #expect(await counter.count == 1)
+    }
+
+    @Test func test_vscodeStyleSave_reloadsOnce() async throws {
+        let dir = try makeTemporaryDirectory()
+        let config = dir.appendingPathComponent("config.toml")
+        try write("[mic]", to: config)
+        let (watcher, counter) = Self.startWatcher(at: config)
+        defer { watcher.stop() }
+
+        let handle = try FileHandle(forWritingTo: config)
+        try handle.truncate(atOffset: 0)
+        try handle.write(contentsOf: Data("[mic]\ndevice_id = \"usb\"".utf8))
+        try handle.close()
+
+        try await waitUntil { await counter.count >= 1 }
+        try await Task.sleep(for: .milliseconds(300))
+        #expect(await counter.count == 1)
+    }
+
+    @Test func test_burstOfWrites_reloadsOnce() async throws {
+        let dir = try makeTemporaryDirectory()
+        let config = dir.appendingPathComponent("config.toml")
+        try write("[mic]", to: config)
+        let (watcher, counter) = Self.startWatcher(at: config)
+        defer { watcher.stop() }
+
+        let handle = try FileHandle(forWritingTo: config)
+        for line in ["\n# one", "\n# two", "\n# three"] {
+            try handle.seekToEnd()
+            try handle.write(contentsOf: Data(line.utf8))
+        }
+        try handle.close()
+
+        try await waitUntil { await counter.count >= 1 }
+        try await Task.sleep(for: .milliseconds(300))
+        #expect(await counter.count == 1)
+    }
+
+    @Test func test_mainFileAndIncludeEdited_reloadsOnce() async throws {
+        let dir = try makeTemporaryDirectory()
+        let config = dir.appendingPathComponent("config.toml")
+        let pack = dir.appendingPathComponent("pack.toml")
+        try write(#"include = ["pack.toml"]"#, to: config)
+        try write("[commands.keys]", to: pack)
+        let (watcher, counter) = Self.startWatcher(at: config)
+        watcher.watchIncludes([pack.path])
+        defer { watcher.stop() }
+
+        try write("[commands.keys]\n\"adam\" = \"a\"", to: pack)
+        try write(#"include = ["pack.toml"]"# + "\n", to: config)
+
+        try await waitUntil { await counter.count >= 1 }
+        try await Task.sleep(for: .milliseconds(300))
+        #expect(await counter.count == 1)
+    }
+
+    @Test func test_backupFileOnly_doesNotReload() async throws {
+        let dir = try makeTemporaryDirectory()
+        let config = dir.appendingPathComponent("config.toml")
+        try write("[mic]", to: config)
+        let (watcher, counter) = Self.startWatcher(at: config)
+        defer { watcher.stop() }
+
+        try write("[mic]", to: dir.appendingPathComponent("config.toml~"))
+        try write("", to: dir.appendingPathComponent(".config.toml.swp"))
+
+        try await Task.sleep(for: .milliseconds(300))
+        #expect(await counter.count == 0)
+    }
+}
@@ -1,3 +1,4 @@
+import Foundation
 import Testing
 import FluidAudio
 @testable import ModalDictationCore
@@ -6,7 +7,10 @@ import FluidAudio
 struct DictationSessionTests {
 
     private static func makeSUT(
-        actions: [String: String] = [:]
+        actions: [String: String] = [:],
+        history: TranscriptHistory? = nil,
+        formatter: (any TranscriptFormatting)? = nil,
+        historyHandler: HistoryActionHandler? = nil
     ) -> (session: DictationSession, engine: MockAudioEngine, recognizer: MockSpeechRecognizer, inserter: MockTextInserter) {
         let engine = MockAudioEngine()
         let recognizer = MockSpeechRecognizer()
@@ -15,7 +19,10 @@ struct DictationSessionTests {
             engine: engine,
             recognizer: recognizer,
             inserter: inserter,
-            actions: actions
+            actions: actions,
+            history: history,
+            formatter: formatter,
+            historyHandler: historyHandler
         )
         return (session, engine, recognizer, inserter)
     }
@@ -35,4 +42,192 @@ struct DictationSessionTests {
         #expect(await inserter.insertedTexts == ["hello", "world"])
     }
 
+    @Test
+    func test_insertedText_recordedInHistory() async throws {
+        let history = TranscriptHistory()
+        let (session, _, recognizer, _) = Self.makeSUT(history: history)
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "hello"))
+        continuation.finish()
+
+        _ = try await session.run()
+        #expect(await history.entries == ["hello"])
+    }
+
+    @Test
+    func test_formatter_appliedBeforeInsertAndHistory() async throws {
+        let history = TranscriptHistory()
+        let formatter = ReplacementFormatter(
+            config: .fixture(global: ["my email": "me@example.com"]),
+            focus: StubFrontmostAppProvider(bundleID: nil)
+        )
+        let (session, _, recognizer, inserter) = Self.makeSUT(history: history, formatter: formatter)
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "my email"))
+        continuation.finish()
+
+        _ = try await session.run()
+        #expect(await inserter.insertedTexts == ["me@example.com"])
+        #expect(await history.entries == ["me@example.com"])
+    }
+
+    @Test
+    func test_scratchPhrase_deletesLastInsertion() async throws {
+        let history = TranscriptHistory()
+        let emitter = MockKeystrokeEmitter()
+        let (session, _, recognizer, inserter) = Self.makeSUT(
+            actions: ["scratch that": "history:scratch"],
+            history: history,
+            historyHandler: HistoryActionHandler(history: history, emitter: emitter)
+        )
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "hello"))
+        continuation.yield(.stub(text: "Scratch that."))
+        continuation.finish()
+
+        _ = try await session.run()
+        #expect(await inserter.insertedTexts == ["hello"])
+        #expect(emitter.emittedCommands == [.keystroke(key: "backspace", modifiers: [], repeat: 5)])
+        #expect(await history.entries.isEmpty)
+    }
+
+    @Test
+    func test_refusedInsert_notRecordedAndSessionContinues() async throws {
+        let history = TranscriptHistory()
+        let recognizer = MockSpeechRecognizer()
+        let inner = MockTextInserter()
+        let session = DictationSession(
+            engine: MockAudioEngine(),
+            recognizer: recognizer,
+            inserter: FocusGuardedTextInserter(
+                inner: inner,
+                denylist: ["com.microsoft.rdc.macos"],
+                focus: StubFrontmostAppProvider(bundleID: "com.microsoft.rdc.macos"),
+                notifier: MockUserNotifier()
+            ),
+            actions: [:],
+            history: history
+        )
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "secret"))
+        continuation.yield(.stub(text: "more"))
+        continuation.finish()
+
+        let event = try await session.run()
+        #expect(event == .completed)
+        #expect(await inner.insertedTexts.isEmpty)
+        #expect(await history.entries.isEmpty)
+    }
+
+    @Test
+    func test_fileSink_scratchPhrase_leavesFocusedAppAlone() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("transcript.txt")
+        let history = TranscriptHistory()
+        let emitter = MockKeystrokeEmitter()
+        let recognizer = MockSpeechRecognizer()
+        let session = DictationSession(
+            engine: MockAudioEngine(),
+            recognizer: recognizer,
+            inserter: FileTranscriptSink(path: file.path),
+            actions: ["scratch that": "history:scratch"],
+            history: history,
+            historyHandler: HistoryActionHandler(history: history, emitter: emitter)
+        )
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "hello"))
+        continuation.yield(.stub(text: "scratch that"))
+        continuation.finish()
+
+        _ = try await session.run()
+        #expect(try String(contentsOf: file, encoding: .utf8) == "hello\n")
+        #expect(await history.entries.isEmpty)
+        #expect(emitter.emittedCommands.isEmpty)
+    }
+
+    @Test
+    func test_voiceTriggerDuringHold_heldSessionRestarted() async throws {
+        var machine = ModeStateMachine(state: .command)
+        _ = machine.handle(.hotkeyPress(.dictationHold))
+        let (session, engine, recognizer, _) = Self.makeSUT(actions: ["command mode": "mode:command"])
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "command mode"))
+        continuation.finish()
+
+        let event = try await session.run()
+        #expect(event == .voiceTrigger(.commandMode))
+        #expect(await engine.stopCalls == 1)
+
+        let transition = machine.handle(.voiceTrigger(.commandMode))
+        #expect(transition.state == .dictation)
+        #expect(transition.sideEffects == [.startDictation])
+        #expect(machine.handle(.hotkeyRelease(.dictationHold)).state == .command)
+    }
+
+    @Test
+    func test_correctionPhrase_editsLastDictationInsteadOfInserting() async throws {
+        let history = TranscriptHistory()
+        let emitter = MockKeystrokeEmitter()
+        let (session, _, recognizer, inserter) = Self.makeSUT(
+            history: history,
+            historyHandler: HistoryActionHandler(
+                history: history, inserter: MockTextInserter(), emitter: emitter, correctionPhrase: "change"
+            )
+        )
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "send teh report"))
+        continuation.yield(.stub(text: "change teh to the"))
+        continuation.finish()
+
+        _ = try await session.run()
+        #expect(await inserter.insertedTexts == ["send teh report"])
+        #expect(emitter.emittedCommands == [
+            .keystroke(key: "left", modifiers: [], repeat: 7),
+            .keystroke(key: "backspace", modifiers: [], repeat: 3),
+            .keystroke(key: "right", modifiers: [], repeat: 7),
+        ])
+        #expect(await history.entries == ["send the report"])
+    }
+
+    @Test
+    func test_correctionInDeniedApp_nothingTypedAndSessionContinues() async throws {
+        let history = TranscriptHistory()
+        await history.record("send teh report")
+        let guarded = FocusGuardedTextInserter(
+            inner: MockTextInserter(), denylist: ["com.microsoft.rdc.macos"],
+            focus: StubFrontmostAppProvider(bundleID: "com.microsoft.rdc.macos"), notifier: nil
+        )
+        let emitter = MockKeystrokeEmitter()
+        let (session, _, recognizer, inserter) = Self.makeSUT(
+            history: history,
+            historyHandler: HistoryActionHandler(
+                history: history, inserter: guarded, emitter: emitter, focusGuard: guarded, correctionPhrase: "change"
+            )
+        )
+
+        let (stream, continuation) = AsyncStream.makeStream(of: ASRResult.self)
+        await recognizer.setResultStream(stream)
+        continuation.yield(.stub(text: "change teh to the"))
+        continuation.yield(.stub(text: "next"))
+        continuation.finish()
+
+        let event = try await session.run()
+        #expect(event == .completed)
+        #expect(emitter.emittedCommands.isEmpty)
+        #expect(await inserter.insertedTexts == ["next"])
+        #expect(await history.entries == ["send teh report", "next"])
+    }
 }
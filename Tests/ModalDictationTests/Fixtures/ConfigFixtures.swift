@@ -31,9 +31,16 @@ extension MicConfig {
 extension SpeechConfig {
     static func fixture(
         timeout: Double? = Double.random(in: 0.1...2.0),
-        autoSleepMinutes: Double? = Double.random(in: 1.0...10.0)
+        autoSleepMinutes: Double? = Double.random(in: 1.0...10.0),
+        maxUtteranceSeconds: Double? = Double.random(in: 10.0...60.0),
+        unloadModelsAfterIdleMinutes: Double? = Double.random(in: 5.0...60.0)
     ) -> SpeechConfig {
-        SpeechConfig(timeout: timeout, autoSleepMinutes: autoSleepMinutes)
+        SpeechConfig(
+            timeout: timeout,
+            autoSleepMinutes: autoSleepMinutes,
+            maxUtteranceSeconds: maxUtteranceSeconds,
+            unloadModelsAfterIdleMinutes: unloadModelsAfterIdleMinutes
+        )
     }
 }
 
@@ -47,13 +54,111 @@ extension CommandsConfig {
     }
 }
 
+extension HistoryConfig {
+    static func fixture(
+        capacity: Int? = .random(in: 1...50),
+        copyToClipboard: Bool? = .random(),
+        correctionPhrase: String? = "change"
+    ) -> HistoryConfig {
+        HistoryConfig(capacity: capacity, copyToClipboard: copyToClipboard, correctionPhrase: correctionPhrase)
+    }
+}
+
+extension OutputConfig {
+    static func fixture(
+        sinks: [TranscriptSinkKind]? = [.paste],
+        filePath: String? = "/tmp/transcripts-\(UUID().uuidString.prefix(8)).txt",
+        command: String? = nil,
+        appAllowlist: [String]? = nil,
+        appDenylist: [String]? = nil
+    ) -> OutputConfig {
+        OutputConfig(
+            sinks: sinks,
+            filePath: filePath,
+            command: command,
+            appAllowlist: appAllowlist,
+            appDenylist: appDenylist
+        )
+    }
+}
+
+extension ReplacementsConfig {
+    static func fixture(
+        global: [String: String] = [:],
+        perApp: [String: [String: String]] = [:]
+    ) -> ReplacementsConfig {
+        ReplacementsConfig(global: global, perApp: perApp)
+    }
+}
+
+extension FormattingConfig {
+    static func fixture(
+        locale: String? = "en_US",
+        units: Bool? = .random(),
+        spokenPunctuation: Bool? = .random(),
+        capitalize: Bool? = .random()
+    ) -> FormattingConfig {
+        FormattingConfig(
+            locale: locale,
+            units: units,
+            spokenPunctuation: spokenPunctuation,
+            capitalize: capitalize
+        )
+    }
+}
+
+extension FeedbackConfig {
+    static func fixture(
+        ttsRate: Double? = .random(in: 0...1),
+        ttsVoice: String? = nil
+    ) -> FeedbackConfig {
+        FeedbackConfig(ttsRate: ttsRate, ttsVoice: ttsVoice)
+    }
+}
+
+extension StartupConfig {
+    static func fixture(
+        mode: Mode? = [.sleeping, .dictation, .command].randomElement()
+    ) -> StartupConfig {
+        StartupConfig(mode: mode)
+    }
+}
+
+extension PrivacyConfig {
+    static func fixture(
+        auditCommands: Bool? = .random(),
+        auditFile: String? = "/tmp/audit-\(UUID().uuidString.prefix(8)).log"
+    ) -> PrivacyConfig {
+        PrivacyConfig(auditCommands: auditCommands, auditFile: auditFile)
+    }
+}
+
 extension AppConfig {
     static func fixture(
         hotkeys: HotkeyConfig = .fixture(),
         mic: MicConfig = .fixture(),
         speech: SpeechConfig = .fixture(),
-        commands: CommandsConfig = .fixture()
+        commands: CommandsConfig = .fixture(),
+        history: HistoryConfig = .fixture(),
+        output: OutputConfig = .fixture(),
+        replacements: ReplacementsConfig = .fixture(),
+        formatting: FormattingConfig = .fixture(),
+        feedback: FeedbackConfig = .fixture(),
+        startup: StartupConfig = .fixture(),
+        privacy: PrivacyConfig = .fixture()
     ) -> AppConfig {
-        AppConfig(hotkeys: hotkeys, mic: mic, speech: speech, commands: commands)
+        AppConfig(
+            hotkeys: hotkeys,
+            mic: mic,
+            speech: speech,
+            commands: commands,
+            history: history,
+            output: output,
+            replacements: replacements,
+            formatting: formatting,
+            feedback: feedback,
+            startup: startup,
+            privacy: privacy
+        )
     }
 }
@@ -12,6 +12,7 @@ actor MockAudioEngine: AudioCapturing {
     var startError: (any Error)?
     private(set) var startCalls = 0
     private(set) var stopCalls = 0
+    private(set) var pauseChanges: [Bool] = []
 
     func setStartError(_ error: any Error) { startError = error }
 
@@ -24,6 +25,10 @@ actor MockAudioEngine: AudioCapturing {
     func stop() async {
         stopCalls += 1
     }
+
+    func setPaused(_ paused: Bool) async {
+        pauseChanges.append(paused)
+    }
 }
 
 actor MockSpeechRecognizer: SpeechRecognizing {
@@ -66,9 +71,64 @@ final class MockKeystrokeEmitter: KeystrokeEmitting, @unchecked Sendable {
 }
 
 actor MockTextInserter: TextInserting {
+    nonisolated let typesIntoFocusedApp: Bool
+    var insertError: (any Error)?
     private(set) var insertedTexts: [String] = []
 
+    init(typesIntoFocusedApp: Bool = true) {
+        self.typesIntoFocusedApp = typesIntoFocusedApp
+    }
+
+    func setInsertError(_ error: any Error) { insertError = error }
+
     func insert(_ text: String) async throws {
+        if let insertError { throw insertError }
         insertedTexts.append(text)
     }
 }
+
+final class MockClipboardWriter: ClipboardWriting, @unchecked Sendable {
+    private(set) var copiedTexts: [String] = []
+
+    func copy(_ text: String) throws {
+        copiedTexts.append(text)
+    }
+}
+
+struct StubFrontmostAppProvider: FrontmostAppProviding {
+    var bundleID: String?
+
+    func frontmostBundleID() async -> String? { bundleID }
+}
+
+actor MockSpeechSynthesizer: SpeechSynthesizing {
+    private(set) var spokenTexts: [String] = []
+
+    func speak(_ text: String) async {
+        spokenTexts.append(text)
+    }
+}
+
+actor MockUserNotifier: UserNotifying {
+    private(set) var messages: [String] = []
+
+    func notify(_ message: String) async {
+        messages.append(message)
+    }
+}
+
+actor MockHistoryPresenter: HistoryPresenting {
+    private(set) var presented: [[String]] = []
+
+    func present(_ entries: [String]) async {
+        presented.append(entries)
+    }
+}
+
+actor MockCommandEventObserver: CommandEventObserving {
+    private(set) var events: [CommandEvent] = []
+
+    func commandExecuted(_ event: CommandEvent) async {
+        events.append(event)
+    }
+}
@@ -72,13 +72,19 @@ actor MockModelProvider: ModelProviding {
         guard let eouManager else { throw ModelStoreError.modelsNotLoaded("mock EOU not loaded") }
         return eouManager
     }
+
+    private(set) var unloadCalls = 0
+
+    func unloadIdleModels() async {
+        unloadCalls += 1
+    }
 }
 
-func makeSpeechRecognizer() async -> (SpeechRecognizer, MockModelProvider) {
+func makeSpeechRecognizer(unloadAfterIdle: Duration? = nil) async -> (SpeechRecognizer, MockModelProvider) {
     let provider = MockModelProvider()
     await provider.setEOUManager(MockStreamingTranscriber())
     await provider.setBatchTranscriber(MockBatchTranscriber())
-    return (SpeechRecognizer(modelStore: provider), provider)
+    return (SpeechRecognizer(modelStore: provider, unloadAfterIdle: unloadAfterIdle), provider)
 }
 
 func makeBuffer(sampleCount: Int, amplitude: Float = 0.0) -> AVAudioPCMBuffer {
@@ -0,0 +1,52 @@
+import Testing
+@testable import ModalDictationCore
+
+@Suite("FocusGuardedTextInserter")
+struct FocusGuardedTextInserterTests {
+
+    @Test func test_insert_deniedApp_throwsRefusalAndNotifies() async throws {
+        let inner = MockTextInserter()
+        let notifier = MockUserNotifier()
+        let guarded = FocusGuardedTextInserter(
+            inner: inner,
+            denylist: ["com.microsoft.rdc.macos"],
+            focus: StubFrontmostAppProvider(bundleID: "com.microsoft.rdc.macos"),
+            notifier: notifier
+        )
+
+        await #expect(throws: FocusGuardError.refused(bundleID: "com.microsoft.rdc.macos")) {
+            try await guarded.insert("secret")
+        }
+
+        #expect(await inner.insertedTexts.isEmpty)
+        #expect(await notifier.messages.count == 1)
+    }
+
+    @Test func test_insert_allowlist_onlyListedAppsReceiveText() async throws {
+        let inner = MockTextInserter()
+        let allowed = FocusGuardedTextInserter(
+            inner: inner, allowlist: ["com.apple.TextEdit"],
+            focus: StubFrontmostAppProvider(bundleID: "com.apple.TextEdit"), notifier: nil
+        )
+        let other = FocusGuardedTextInserter(
+            inner: inner, allowlist: ["com.apple.TextEdit"],
+            focus: StubFrontmostAppProvider(bundleID: "com.apple.Safari"), notifier: nil
+        )
+
+        try await allowed.insert("yes")
+        await #expect(throws: FocusGuardError.self) {
+            try await other.insert("no")
+        }
+
+        #expect(await inner.insertedTexts == ["yes"])
+    }
+
+    @Test func test_insert_noLists_passesThrough() async throws {
+        let inner = MockTextInserter()
+        let guarded = FocusGuardedTextInserter(inner: inner, focus: StubFrontmostAppProvider(bundleID: nil), notifier: nil)
+
+        try await guarded.insert("hello")
+
+        #expect(await inner.insertedTexts == ["hello"])
+    }
+}
@@ -0,0 +1,176 @@
+import Foundation
+import Testing
+@testable import ModalDictationCore
+
+@Suite("ReplacementFormatter")
+struct ReplacementFormatterTests {
+
+    private static let config = ReplacementsConfig.fixture(
+        global: ["my email": "me@example.com", "signature": "Thanks"],
+        perApp: ["com.apple.mail": ["signature": "Best regards"]]
+    )
+
+    @Test func test_format_globalReplacement_caseInsensitive() async {
+        let formatter = ReplacementFormatter(config: Self.config, focus: StubFrontmostAppProvider(bundleID: nil))
+
+        #expect(await formatter.format("send it to My Email please") == "send it to me@example.com please")
+    }
+
+    @Test func test_format_focusedAppOverridesGlobal() async {
+        let formatter = ReplacementFormatter(
+            config: Self.config, focus: StubFrontmostAppProvider(bundleID: "com.apple.mail")
+        )
+
+        #expect(await formatter.format("signature") == "Best regards")
+    }
+
+    @Test func test_format_otherAppUsesGlobal() async {
+        let formatter = ReplacementFormatter(
+            config: Self.config, focus: StubFrontmostAppProvider(bundleID: "com.apple.Safari")
+        )
+
+        #expect(await formatter.format("signature") == "Thanks")
+    }
+
+    @Test func test_apply_matchesWholeWordsOnly() {
+        let result = ReplacementFormatter.apply(["cat": "dog"], to: "concatenate the cat")
+
+        #expect(result == "concatenate the dog")
+    }
+
+    @Test func test_apply_expansionNotRewrittenByShorterPhrase() {
+        let result = ReplacementFormatter.apply(["btw": "by the way", "way": "road"], to: "btw that way")
+
+        #expect(result == "by the way that road")
+    }
+
+    @Test func test_apply_phraseWithSymbolEdges() {
+        let result = ReplacementFormatter.apply([":shrug:": "¯\\_(ツ)_/¯", "c++": "C++ 20"], to: "well :shrug: c++ c++x")
+
+        #expect(result == "well ¯\\_(ツ)_/¯ C++ 20 C++ 20x")
+    }
+}
+
+@Suite("UnitsFormatter")
+struct UnitsFormatterTests {
+
+    private static let enUS = Locale(identifier: "en_US")
+
+    static let amountCases: [(input: String, expected: String)] = [
+        ("fifty euros", "€50"),
+        ("it costs twenty five dollars.", "it costs $25."),
+        ("three point five kilometers", "3.5 km"),
+        ("ten percent", "10%"),
+        ("12 kilograms", "12 kg"),
+        ("zero point two five liters", "0.25 L"),
+        ("one hundred twenty dollars", "$120"),
+        ("two thousand five hundred euros", "€2,500"),
+    ]
+
+    @Test(arguments: amountCases)
+    func test_apply_formatsSpokenAmounts(input: String, expected: String) {
+        #expect(UnitsFormatter.apply(to: input, locale: Self.enUS) == expected)
+    }
+
+    @Test func test_apply_usesLocaleDecimalSeparator() {
+        let result = UnitsFormatter.apply(to: "three point five kilometers", locale: Locale(identifier: "de_DE"))
+
+        #expect(result == "3,5 km")
+    }
+
+    @Test func test_apply_leavesNumbersWithoutUnitsAlone() {
+        #expect(UnitsFormatter.apply(to: "I have three cats", locale: Self.enUS) == "I have three cats")
+    }
+}
+
+@Suite("SpokenPunctuationFormatter")
+struct SpokenPunctuationFormatterTests {
+
+    static let punctuationCases: [(input: String, expected: String)] = [
+        ("hello comma world period", "hello, world."),
+        ("is it done question mark", "is it done?"),
+        ("Dear Sam, new line thanks", "Dear Sam,\nthanks"),
+        ("first new paragraph second", "first\n\nsecond"),
+        ("call me open paren maybe close paren", "call me (maybe)"),
+        ("wow Exclamation point.", "wow!"),
+    ]
+
+    @Test(arguments: punctuationCases)
+    func test_apply_convertsSpokenMarks(input: String, expected: String) {
+        #expect(SpokenPunctuationFormatter.apply(to: input) == expected)
+    }
+
+    @Test func test_apply_literalTypesTheWord() {
+        #expect(SpokenPunctuationFormatter.apply(to: "type literal comma here") == "type comma here")
+    }
+
+    @Test func test_apply_literalBeforeOrdinaryWord_isDictated() {
+        #expect(SpokenPunctuationFormatter.apply(to: "a literal translation period") == "a literal translation.")
+        #expect(SpokenPunctuationFormatter.apply(to: "say literal new line") == "say new line")
+    }
+}
+
+@Suite("CapitalizationFormatter")
+struct CapitalizationFormatterTests {
+
+    @Test func test_apply_capitalizesSentenceStarts() {
+        let result = CapitalizationFormatter.apply(to: "hello. is it me? yes!\nnext line")
+
+        #expect(result == "Hello. Is it me? Yes!\nNext line")
+    }
+
+    @Test func test_apply_ignoresDotsInsideWords() {
+        #expect(CapitalizationFormatter.apply(to: "mail example.com about 3.5 km") == "Mail example.com about 3.5 km")
+    }
+
+    @Test func test_format_continuesSentenceAcrossUtterances() async {
+        let formatter = CapitalizationFormatter()
+
+        #expect(await formatter.format("so I was thinking") == "So I was thinking")
+        #expect(await formatter.format("we could go.") == "we could go.")
+        #expect(await formatter.format("then again") == "Then again")
+    }
+}
+
+@Suite("FormattingPipeline")
+struct FormattingPipelineTests {
+
+    @Test func test_make_chainsDefaultStages() async {
+        let config = AppConfig.fixture(
+            replacements: .fixture(),
+            formatting: FormattingConfig(locale: "en_US")
+        )
+        let pipeline = FormattingPipeline.make(from: config)
+
+        #expect(await pipeline.format("it costs fifty euros") == "It costs €50")
+    }
+
+    @Test func test_make_defaultLeavesPunctuationWordsInProse() async {
+        let config = AppConfig.fixture(
+            replacements: .fixture(),
+            formatting: FormattingConfig(locale: "en_US")
+        )
+        let pipeline = FormattingPipeline.make(from: config)
+
+        #expect(await pipeline.format("the trial period ended") == "The trial period ended")
+    }
+
+    @Test func test_make_spokenPunctuationEnabled_convertsMarks() async {
+        let config = AppConfig.fixture(
+            replacements: .fixture(),
+            formatting: FormattingConfig(locale: "en_US", spokenPunctuation: true)
+        )
+        let pipeline = FormattingPipeline.make(from: config)
+
+        #expect(await pipeline.format("it costs fifty euros period that is fine") == "It costs €50. That is fine")
+    }
+
+    @Test func test_format_runsStagesInOrder() async {
+        let pipeline = FormattingPipeline(stages: [
+            ReplacementFormatter(config: .fixture(global: ["the fee": "ten percent"]), focus: StubFrontmostAppProvider()),
+            UnitsFormatter(locale: Locale(identifier: "en_US")),
+        ])
+
+        #expect(await pipeline.format("charge the fee") == "charge 10%")
+    }
+}
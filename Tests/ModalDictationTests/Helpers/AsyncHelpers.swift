@@ -12,3 +12,17 @@ func awaitCompletion(of task: Task<Void, Never>, timeout: Duration = .seconds(2)
         group.cancelAll()
     }
 }
+
+func waitUntil(
+    timeout: Duration = .seconds(2),
+    _ condition: @Sendable () async -> Bool
+) async throws {
+    let deadline = ContinuousClock.now + timeout
+    while !(await condition()) {
+        guard ContinuousClock.now < deadline else {
+            struct Elapsed: Error {}
+            throw Elapsed()
+        }
+        try await Task.sleep(for: .milliseconds(10))
+    }
+}
@@ -0,0 +1,231 @@
+import Testing
+@testable import ModalDictationCore
+
+@Suite("TranscriptHistory")
+struct TranscriptHistoryTests {
+
+    @Test func test_record_beyondCapacity_dropsOldest() async {
+        let history = TranscriptHistory(capacity: 2)
+        await history.record("one")
+        await history.record("two")
+        await history.record("three")
+
+        #expect(await history.entries == ["two", "three"])
+    }
+
+    @Test func test_item_numbersFromMostRecent() async {
+        let history = TranscriptHistory()
+        await history.record("first")
+        await history.record("second")
+
+        #expect(await history.item(1) == "second")
+        #expect(await history.item(2) == "first")
+        #expect(await history.item(3) == nil)
+        #expect(await history.item(0) == nil)
+    }
+
+    @Test func test_initFromConfig_usesConfiguredCapacity() async {
+        let history = TranscriptHistory(config: .fixture(capacity: 1))
+        await history.record("one")
+        await history.record("two")
+
+        #expect(await history.entries == ["two"])
+    }
+}
+
+@Suite("HistoryActionHandler")
+struct HistoryActionHandlerTests {
+
+    @Test func test_inject_insertsNumberedItem() async throws {
+        let history = TranscriptHistory()
+        await history.record("older")
+        await history.record("newer")
+        let inserter = MockTextInserter()
+        let handler = HistoryActionHandler(history: history, inserter: inserter)
+
+        let handled = try await handler.handle(.action("history:inject", argument: 2))
+
+        #expect(handled)
+        #expect(await inserter.insertedTexts == ["older"])
+    }
+
+    @Test func test_copyToClipboard_copiesInsteadOfInserting() async throws {
+        let history = TranscriptHistory()
+        await history.record("hello")
+        let inserter = MockTextInserter()
+        let clipboard = MockClipboardWriter()
+        let handler = HistoryActionHandler(
+            history: history, inserter: inserter, clipboard: clipboard, copyToClipboard: true
+        )
+
+        _ = try await handler.handle(.action("history:paste_last"))
+
+        #expect(clipboard.copiedTexts == ["hello"])
+        #expect(await inserter.insertedTexts.isEmpty)
+    }
+
+    @Test func test_emptyHistory_isHandledWithoutInserting() async throws {
+        let inserter = MockTextInserter()
+        let handler = HistoryActionHandler(history: TranscriptHistory(), inserter: inserter)
+
+        let handled = try await handler.handle(.action("history:paste_last"))
+
+        #expect(handled)
+        #expect(await inserter.insertedTexts.isEmpty)
+    }
+
+    @Test func test_readBack_speaksLastWithoutInserting() async throws {
+        let history = TranscriptHistory()
+        await history.record("hello there")
+        let inserter = MockTextInserter()
+        let speaker = MockSpeechSynthesizer()
+        let handler = HistoryActionHandler(history: history, inserter: inserter, speaker: speaker)
+
+        let handled = try await handler.handle(.action("history:read_back"))
+
+        #expect(handled)
+        #expect(await speaker.spokenTexts == ["hello there"])
+        #expect(await inserter.insertedTexts.isEmpty)
+    }
+
+    @Test func test_readBack_pausesCaptureWhileSpeaking() async throws {
+        let history = TranscriptHistory()
+        await history.record("hello there")
+        let engine = MockAudioEngine()
+        let speaker = MockSpeechSynthesizer()
+        let handler = HistoryActionHandler(history: history, inserter: MockTextInserter(), speaker: speaker, capture: engine)
+
+        _ = try await handler.handle(.action("history:read_back"))
+
+        #expect(await speaker.spokenTexts == ["hello there"])
+        #expect(await engine.pauseChanges == [true, false])
+    }
+
+    @Test func test_readBack_withoutSpeaker_notHandled() async throws {
+        let history = TranscriptHistory()
+        await history.record("hello there")
+        let handler = HistoryActionHandler(history: history, inserter: MockTextInserter())
+
+        let handled = try await handler.handle(.action("history:read_back"))
+
+        #expect(!handled)
+    }
+
+    @Test func test_show_presentsEntriesMostRecentFirst() async throws {
+        let history = TranscriptHistory()
+        await history.record("older")
+        await history.record("newer")
+        let presenter = MockHistoryPresenter()
+        let handler = HistoryActionHandler(history: history, inserter: MockTextInserter(), presenter: presenter)
+
+        let handled = try await handler.handle(.action("history:show"))
+
+        #expect(handled)
+        #expect(await presenter.presented == [["newer", "older"]])
+    }
+
+    @Test func test_scratch_backspacesOverLastAndForgetsIt() async throws {
+        let history = TranscriptHistory()
+        await history.record("keep")
+        await history.record("oops")
+        let emitter = MockKeystrokeEmitter()
+        let handler = HistoryActionHandler(history: history, emitter: emitter)
+
+        _ = try await handler.handle(.action("history:scratch"))
+
+        #expect(emitter.emittedCommands == [.keystroke(key: "backspace", modifiers: [], repeat: 4)])
+        #expect(await history.entries == ["keep"])
+    }
+
+    @Test func test_scratch_deniedApp_sendsNoKeystrokesAndKeepsEntry() async throws {
+        let history = TranscriptHistory()
+        await history.record("oops")
+        let guarded = FocusGuardedTextInserter(
+            inner: MockTextInserter(), denylist: ["com.microsoft.rdc.macos"],
+            focus: StubFrontmostAppProvider(bundleID: "com.microsoft.rdc.macos"), notifier: nil
+        )
+        let emitter = MockKeystrokeEmitter()
+        let handler = HistoryActionHandler(history: history, emitter: emitter, focusGuard: guarded)
+
+        let handled = try await handler.handle(.action("history:scratch"))
+
+        #expect(handled)
+        #expect(emitter.emittedCommands.isEmpty)
+        #expect(await history.entries == ["oops"])
+    }
+
+    @Test func test_correct_retypesTargetInLastDictation() async throws {
+        let history = TranscriptHistory()
+        await history.record("send teh report today")
+        let inserter = MockTextInserter()
+        let emitter = MockKeystrokeEmitter()
+        let handler = HistoryActionHandler(
+            history: history, inserter: inserter, emitter: emitter, correctionPhrase: "change"
+        )
+
+        let corrected = try await handler.correct("Change teh to the.")
+
+        #expect(corrected)
+        #expect(emitter.emittedCommands == [
+            .keystroke(key: "left", modifiers: [], repeat: 13),
+            .keystroke(key: "backspace", modifiers: [], repeat: 3),
+            .keystroke(key: "right", modifiers: [], repeat: 13),
+        ])
+        #expect(await inserter.insertedTexts == ["the"])
+        #expect(await history.last == "send the report today")
+    }
+
+    @Test func test_correct_targetMissing_returnsFalse() async throws {
+        let history = TranscriptHistory()
+        await history.record("hello world")
+        let emitter = MockKeystrokeEmitter()
+        let handler = HistoryActionHandler(
+            history: history, inserter: MockTextInserter(), emitter: emitter, correctionPhrase: "change"
+        )
+
+        #expect(try await handler.correct("change the oil to synthetic") == false)
+        #expect(emitter.emittedCommands.isEmpty)
+    }
+
+    @Test func test_correct_matchesWholeWordsOnly() async throws {
+        let history = TranscriptHistory()
+        await history.record("a cat today")
+        let emitter = MockKeystrokeEmitter()
+        let handler = HistoryActionHandler(
+            history: history, inserter: MockTextInserter(), emitter: emitter, correctionPhrase: "change"
+        )
+
+        #expect(try await handler.correct("change a to the"))
+        #expect(await history.last == "the cat today")
+        #expect(emitter.emittedCommands.first == .keystroke(key: "left", modifiers: [], repeat: 10))
+    }
+
+    @Test func test_correct_deniedApp_throwsBeforeAnyKeystroke() async throws {
+        let history = TranscriptHistory()
+        await history.record("send teh report")
+        let inner = MockTextInserter()
+        let guarded = FocusGuardedTextInserter(
+            inner: inner, denylist: ["com.microsoft.rdc.macos"],
+            focus: StubFrontmostAppProvider(bundleID: "com.microsoft.rdc.macos"), notifier: nil
+        )
+        let emitter = MockKeystrokeEmitter()
+        let handler = HistoryActionHandler(
+            history: history, inserter: guarded, emitter: emitter, focusGuard: guarded, correctionPhrase: "change"
+        )
+
+        await #expect(throws: FocusGuardError.self) {
+            try await handler.correct("change teh to the")
+        }
+
+        #expect(emitter.emittedCommands.isEmpty)
+        #expect(await inner.insertedTexts.isEmpty)
+        #expect(await history.last == "send teh report")
+    }
+
+    @Test func test_unrelatedCommand_notHandled() async throws {
+        let handler = HistoryActionHandler(history: TranscriptHistory(), inserter: MockTextInserter())
+
+        #expect(try await handler.handle(.action("mode:dictation")) == false)
+        #expect(try await handler.handle(.keystroke(key: "a", modifiers: [], repeat: 1)) == false)
+    }
+}
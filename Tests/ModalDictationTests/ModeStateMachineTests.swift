@@ -135,7 +135,7 @@ struct ModeStateMachineTests {
     }
 
     @Test
-    func test_holdRelease_afterInterveningTransition_isNoOp() {
+    func test_holdRelease_afterSleepAndWakeDuringHold_restoresPriorMode() {
         var sut = ModeStateMachine(state: .command, lastActiveMode: .command)
         _ = sut.handle(.hotkeyPress(.dictationHold))
         _ = sut.handle(.hotkeyPress(.sleepToggle))
@@ -143,8 +143,8 @@ struct ModeStateMachineTests {
 
         let release = sut.handle(.hotkeyRelease(.dictationHold))
 
-        #expect(release.state == .dictation)
-        #expect(release.sideEffects == [])
+        #expect(release.state == .command)
+        #expect(release.sideEffects == [.stopDictation, .startCommands])
         #expect(sut.modeBeforeHold == nil)
     }
 
@@ -218,4 +218,106 @@ struct ModeStateMachineTests {
         #expect(transition.state == .sleeping)
         #expect(transition.sideEffects == [.stopCommands])
     }
+
+    @Test
+    func test_sleepTriggers_recordedOnTransition() {
+        var spoken = ModeStateMachine(state: .dictation)
+        var timedOut = ModeStateMachine(state: .dictation)
+
+        let bySleepWord = spoken.handle(.voiceTrigger(.sleep))
+        let byTimer = timedOut.handle(.autoSleepFired)
+
+        #expect(bySleepWord.state == .sleeping)
+        #expect(bySleepWord.trigger == .voiceTrigger(.sleep))
+        #expect(byTimer.state == .sleeping)
+        #expect(byTimer.trigger == .autoSleepFired)
+    }
+
+    @Test
+    func test_mutePhrase_stopsListeningFromAnyMode() {
+        var sut = ModeStateMachine(state: .command)
+        let transition = sut.handle(.voiceTrigger(.mute))
+
+        #expect(transition.state == .muted)
+        #expect(transition.sideEffects == [.stopCommands])
+        #expect(sut.lastActiveMode == .command)
+    }
+
+    @Test
+    func test_muted_ignoresVoiceAndAutoSleep() {
+        var sut = ModeStateMachine(state: .muted)
+
+        #expect(sut.handle(.voiceTrigger(.wakeUp)).state == .muted)
+        #expect(sut.handle(.voiceTrigger(.dictationMode)).state == .muted)
+        #expect(sut.handle(.autoSleepFired).state == .muted)
+    }
+
+    @Test
+    func test_muted_dictationHoldDoesNotReopenMic() {
+        var sut = ModeStateMachine(state: .muted, lastActiveMode: .dictation)
+
+        let press = sut.handle(.hotkeyPress(.dictationHold))
+        let release = sut.handle(.hotkeyRelease(.dictationHold))
+
+        #expect(press.state == .muted)
+        #expect(press.sideEffects.isEmpty)
+        #expect(release.state == .muted)
+        #expect(sut.modeBeforeHold == nil)
+    }
+
+    @Test
+    func test_mutedSleepToggle_resumesLastActiveMode() {
+        var sut = ModeStateMachine(state: .muted, lastActiveMode: .command)
+        let transition = sut.handle(.hotkeyPress(.sleepToggle))
+
+        #expect(transition.state == .command)
+        #expect(transition.sideEffects == [.startCommands])
+    }
+
+    @Test
+    func test_voiceModeDuringHold_appliesOnRelease() {
+        var sut = ModeStateMachine()
+        _ = sut.handle(.hotkeyPress(.dictationHold))
+
+        let during = sut.handle(.voiceTrigger(.commandMode))
+        let release = sut.handle(.hotkeyRelease(.dictationHold))
+
+        #expect(during.state == .dictation)
+        #expect(during.sideEffects == [.startDictation])
+        #expect(release.state == .command)
+        #expect(release.sideEffects == [.stopDictation, .startCommands])
+    }
+
+    @Test
+    func test_sleepRequestsDuringHold_sleepOnRelease() {
+        var spoken = ModeStateMachine(state: .command)
+        var toggled = ModeStateMachine(state: .command)
+        _ = spoken.handle(.hotkeyPress(.dictationHold))
+        _ = toggled.handle(.hotkeyPress(.dictationHold))
+
+        #expect(spoken.handle(.voiceTrigger(.sleep)).state == .dictation)
+        #expect(toggled.handle(.hotkeyPress(.sleepToggle)).state == .dictation)
+        #expect(spoken.handle(.hotkeyRelease(.dictationHold)).state == .sleeping)
+        #expect(toggled.handle(.hotkeyRelease(.dictationHold)).state == .sleeping)
+    }
+
+    @Test
+    func test_autoSleepDuringHold_isIgnored() {
+        var sut = ModeStateMachine(state: .command)
+        _ = sut.handle(.hotkeyPress(.dictationHold))
+
+        #expect(sut.handle(.autoSleepFired).state == .dictation)
+        #expect(sut.handle(.hotkeyRelease(.dictationHold)).state == .command)
+    }
+
+    @Test
+    func test_muteDuringHold_takesEffectImmediately() {
+        var sut = ModeStateMachine()
+        _ = sut.handle(.hotkeyPress(.dictationHold))
+
+        let transition = sut.handle(.voiceTrigger(.mute))
+
+        #expect(transition.state == .muted)
+        #expect(sut.handle(.hotkeyRelease(.dictationHold)).state == .muted)
+    }
 }
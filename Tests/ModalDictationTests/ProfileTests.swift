@@ -0,0 +1,111 @@
+import Foundation
+import Testing
+@testable import ModalDictationCore
+
+@Suite("Profiles")
+struct ProfileTests {
+
+    private static let toml = """
+    [speech]
+    timeout = 1.0
+
+    [mic]
+    device_id = "builtin"
+
+    [profiles.meeting.speech]
+    timeout = 3.0
+
+    [profiles.quiet.output]
+    sinks = ["file"]
+    """
+
+    @Test func test_parse_activeProfile_overridesBase() throws {
+        let config = try ConfigReader.parse(Self.toml + "\n[profile]\nactive = \"meeting\"")
+
+        #expect(config.speech.timeout == 3.0)
+        #expect(config.mic.deviceID == "builtin")
+        #expect(config.activeProfile == "meeting")
+        #expect(config.profileNames == ["meeting", "quiet"])
+    }
+
+    @Test func test_read_setOverride_winsOverActiveProfile() throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write(Self.toml, to: file)
+
+        let config = try ConfigReader.read(from: file.path, overrides: [
+            "profile.active": "meeting",
+            "speech.timeout": "5.0",
+        ])
+
+        #expect(config.activeProfile == "meeting")
+        #expect(config.speech.timeout == 5.0)
+    }
+
+    @Test func test_parse_profileNamedDefault_throws() {
+        #expect {
+            try ConfigReader.parse("[profiles.default.speech]\ntimeout = 2.0")
+        } throws: { error in
+            guard case let ConfigError.invalidValue(key, _) = error else { return false }
+            return key == "profiles.default"
+        }
+    }
+
+    @Test func test_parse_unknownProfile_throws() {
+        #expect(throws: ConfigError.self) {
+            try ConfigReader.parse(Self.toml + "\n[profile]\nactive = \"party\"")
+        }
+    }
+
+    @Test func test_switcher_profileAction_broadcastsMergedConfig() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write(Self.toml, to: file)
+        let switcher = try ProfileSwitcher(path: file.path)
+        var updates = await switcher.updates().makeAsyncIterator()
+
+        let handled = try await switcher.handle(.action("profile:meeting"))
+        let update = await updates.next()
+
+        #expect(handled)
+        #expect(update?.speech.timeout == 3.0)
+        #expect(await switcher.config.activeProfile == "meeting")
+    }
+
+    @Test func test_switcher_defaultProfile_restoresBase() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write(Self.toml + "\n[profile]\nactive = \"meeting\"", to: file)
+        let switcher = try ProfileSwitcher(path: file.path)
+
+        _ = try await switcher.handle(.action("profile:default"))
+
+        #expect(await switcher.config.speech.timeout == 1.0)
+        #expect(await switcher.config.activeProfile == nil)
+    }
+
+    @Test func test_switcher_watching_reloadsWhenIncludeChanges() async throws {
+        let dir = try makeTemporaryDirectory()
+        let file = dir.appendingPathComponent("config.toml")
+        let pack = dir.appendingPathComponent("pack.toml")
+        try write("[commands.keys]\n\"adam\" = \"a\"", to: pack)
+        try write(#"include = ["pack.toml"]"#, to: file)
+        let switcher = try ProfileSwitcher(path: file.path)
+        var updates = await switcher.updates().makeAsyncIterator()
+        await switcher.startWatching()
+
+        try write("[commands.keys]\n\"adam\" = \"x\"", to: pack)
+        let update = await updates.next()
+        await switcher.stopWatching()
+
+        #expect(update?.commands.keys == ["adam": "x"])
+    }
+
+    @Test func test_switcher_unknownProfile_keepsCurrentConfig() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("config.toml")
+        try write(Self.toml, to: file)
+        let switcher = try ProfileSwitcher(path: file.path)
+
+        await #expect(throws: ConfigError.self) {
+            try await switcher.activate("party")
+        }
+        #expect(await switcher.config.speech.timeout == 1.0)
+    }
+}
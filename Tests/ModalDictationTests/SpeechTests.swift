@@ -73,6 +73,57 @@ struct SpeechRecognizerTests {
         let mode = await recognizer.mode
         #expect(mode == .idle)
     }
+
+    @Test func test_stop_withUnloadAfterIdle_unloadsModels() async throws {
+        let (recognizer, provider) = await makeSpeechRecognizer(unloadAfterIdle: .milliseconds(10))
+
+        let (audio, _) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        _ = try await recognizer.startDictation(audio: audio)
+        await recognizer.stop()
+
+        try await waitUntil { await provider.unloadCalls == 1 }
+    }
+
+    @Test func test_initFromConfig_unloadsAfterConfiguredMinutes() async throws {
+        let provider = MockModelProvider()
+        await provider.setEOUManager(MockStreamingTranscriber())
+        await provider.setBatchTranscriber(MockBatchTranscriber())
+        let recognizer = SpeechRecognizer(
+            modelStore: provider,
+            config: .fixture(unloadModelsAfterIdleMinutes: 0.0005)
+        )
+
+        let (audio, _) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        _ = try await recognizer.startDictation(audio: audio)
+        await recognizer.stop()
+
+        try await waitUntil { await provider.unloadCalls == 1 }
+    }
+
+    @Test func test_modeSwitch_doesNotUnloadBetweenSessions() async throws {
+        let (recognizer, provider) = await makeSpeechRecognizer(unloadAfterIdle: .milliseconds(50))
+
+        let (dictation, _) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        _ = try await recognizer.startDictation(audio: dictation)
+        await recognizer.stop()
+        let (commands, _) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        _ = try await recognizer.startCommands(audio: commands, commandsConfig: CommandsConfig())
+        try await Task.sleep(for: .milliseconds(150))
+
+        #expect(await provider.unloadCalls == 0)
+    }
+
+    @Test func test_restartBeforeIdleDelay_keepsModelsLoaded() async throws {
+        let (recognizer, provider) = await makeSpeechRecognizer(unloadAfterIdle: .seconds(60))
+
+        let (first, _) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        _ = try await recognizer.startDictation(audio: first)
+        await recognizer.stop()
+        let (second, _) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        _ = try await recognizer.startDictation(audio: second)
+
+        #expect(await provider.unloadCalls == 0)
+    }
 }
 
 @Suite("HybridRecognizer")
@@ -146,6 +197,56 @@ struct HybridRecognizerTests {
         #expect(texts == ["flushed"])
         #expect(streaming.finishCalls == 1)
     }
+
+    @Test func test_maxUtteranceExceeded_forcesTranscriptionWithoutEou() async throws {
+        let streaming = MockStreamingTranscriber()
+        let batch = MockBatchTranscriber()
+        await batch.setResult(.stub(text: "forced"))
+
+        let recognizer = HybridRecognizer(
+            streamingTranscriber: streaming,
+            batchTranscriber: batch,
+            maxUtteranceSeconds: 1.0
+        )
+
+        let (audioStream, audioContinuation) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        let results = await recognizer.start(audio: audioStream)
+
+        audioContinuation.yield(makeBuffer(sampleCount: 17000, amplitude: 0.5))
+        var resultIter = results.makeAsyncIterator()
+        let first = await resultIter.next()
+        audioContinuation.finish()
+
+        #expect(first?.text == "forced")
+        #expect(streaming.resetCalls == 1)
+    }
+
+    @Test func test_maxUtteranceExceeded_shortTailPaddedNotDropped() async throws {
+        let streaming = MockStreamingTranscriber()
+        let batch = MockBatchTranscriber()
+        await batch.setResult(.stub(text: "chunk"))
+
+        let recognizer = HybridRecognizer(
+            streamingTranscriber: streaming,
+            batchTranscriber: batch,
+            maxUtteranceSeconds: 1.0
+        )
+
+        let (audioStream, audioContinuation) = AsyncStream.makeStream(of: AVAudioPCMBuffer.self)
+        let results = await recognizer.start(audio: audioStream)
+
+        audioContinuation.yield(makeBuffer(sampleCount: 16000, amplitude: 0.5))
+        audioContinuation.yield(makeBuffer(sampleCount: 4000, amplitude: 0.5))
+        audioContinuation.finish()
+
+        var texts: [String] = []
+        for await result in results { texts.append(result.text) }
+
+        let calls = await batch.calls
+        #expect(texts == ["chunk", "chunk"])
+        #expect(calls.map(\.count) == [16000, 16000])
+        #expect(calls.last?.suffix(12000).allSatisfy { $0 == 0 } == true)
+    }
 }
 
 @Suite("VocabularyBuilder")
@@ -0,0 +1,62 @@
+import Foundation
+import Testing
+@testable import ModalDictationCore
+
+@Suite("TranscriptSinks")
+struct TranscriptSinkTests {
+
+    @Test func test_fileSink_appendsOneLinePerTranscript() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("transcripts.txt")
+        let sink = FileTranscriptSink(path: file.path)
+
+        try await sink.insert("first")
+        try await sink.insert("second")
+
+        #expect(try String(contentsOf: file, encoding: .utf8) == "first\nsecond\n")
+    }
+
+    @Test func test_commandSink_pipesTranscriptToStdin() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("piped.txt")
+        let sink = CommandTranscriptSink(command: "cat > '\(file.path)'")
+
+        try await sink.insert("hello")
+
+        #expect(try String(contentsOf: file, encoding: .utf8) == "hello")
+    }
+
+    @Test func test_commandSink_nonZeroExit_throws() async {
+        let sink = CommandTranscriptSink(command: "exit 3")
+
+        await #expect(throws: TranscriptSinkError.commandFailed(command: "exit 3", status: 3)) {
+            try await sink.insert("ignored")
+        }
+    }
+
+    @Test func test_commandSink_transcriptLargerThanPipeBuffer_delivered() async throws {
+        let file = try makeTemporaryDirectory().appendingPathComponent("large.txt")
+        let sink = CommandTranscriptSink(command: "cat > '\(file.path)'")
+        let text = String(repeating: "word ", count: 40_000)
+
+        try await sink.insert(text)
+
+        #expect(try String(contentsOf: file, encoding: .utf8) == text)
+    }
+
+    @Test func test_make_commandSinkWithoutCommand_throws() {
+        #expect(throws: ConfigError.self) {
+            try TranscriptSinks.make(from: .fixture(sinks: [.command], command: nil))
+        }
+    }
+
+    @Test func test_fanOut_deliversToRemainingSinksAfterFailure() async throws {
+        let failing = MockTextInserter()
+        await failing.setInsertError(InserterError.pasteboardWriteFailed)
+        let working = MockTextInserter()
+        let fanOut = FanOutTextInserter(sinks: [failing, working])
+
+        await #expect(throws: InserterError.pasteboardWriteFailed) {
+            try await fanOut.insert("hello")
+        }
+        #expect(await working.insertedTexts == ["hello"])
+    }
+}
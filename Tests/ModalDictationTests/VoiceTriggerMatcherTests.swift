@@ -9,6 +9,7 @@ struct VoiceTriggerMatcherTests {
         ("wake up", "app:wake", .wakeUp),
         ("dictation mode", "mode:dictation", .dictationMode),
         ("command mode", "mode:command", .commandMode),
+        ("mute everything", "app:mute", .mute),
     ]
 
     @Test(arguments: triggerCases)
@@ -29,6 +30,12 @@ struct VoiceTriggerMatcherTests {
         #expect(matcher.match("okay go to sleep now") == .sleep)
     }
 
+    @Test
+    func test_mutePhrase_outranksLongerTrigger() {
+        let matcher = VoiceTriggerMatcher(actions: ["mute": "app:mute", "switch to dictation mode": "mode:dictation"])
+        #expect(matcher.match("mute and switch to dictation mode") == .mute)
+    }
+
     @Test
     func test_noMatchingPhrase_returnsNil() {
         let matcher = VoiceTriggerMatcher(actions: ["go to sleep": "app:sleep"])